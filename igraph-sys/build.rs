@@ -0,0 +1,226 @@
+//! Locates libigraph and tells cargo how to link it.
+//!
+//! The library is searched, in order:
+//! 1. in the bundled sources under `vendor/igraph`, built with cmake into a static library, when
+//!    the `vendored` feature is on; with `vendored-download`, missing sources are fetched first;
+//! 2. under the prefix given by the `IGRAPH_DIR` environment variable (`$IGRAPH_DIR/lib`, `$IGRAPH_DIR/include`);
+//! 3. through pkg-config;
+//! 4. under `/usr/local`, where igraph's own `cmake --install` puts it by default.
+//!
+//! The include directory is exported to dependents as `DEP_IGRAPH_INCLUDE`; with the `bindgen`
+//! feature, the bindings are regenerated from the `igraph.h` found there instead of using the
+//! checked-in `bindings.rs`.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// The igraph release the bindings were generated from.
+const IGRAPH_VERSION: &str = "1.0.0";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=IGRAPH_DIR");
+    println!("cargo:rerun-if-env-changed=IGRAPH_CXX_STDLIB");
+
+    let statik = env::var_os("CARGO_FEATURE_STATIC").is_some();
+
+    let include = if env::var_os("CARGO_FEATURE_VENDORED").is_some() {
+        build_vendored()
+    } else if let Some(dir) = env::var_os("IGRAPH_DIR") {
+        link_prefix(Path::new(&dir), statik)
+    } else if let Some(include) = probe_pkg_config(statik) {
+        include
+    } else {
+        link_prefix(Path::new("/usr/local"), statik)
+    };
+
+    println!("cargo:include={}", include.display());
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include);
+}
+
+/// Writes the bindings for the headers in `include` to `$OUT_DIR/bindings.rs`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include: &Path) {
+    let header = include.join("igraph.h");
+    if !header.is_file() {
+        panic!("cannot generate bindings: {} not found", header.display());
+    }
+    println!("cargo:rerun-if-changed={}", header.display());
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("bindings.rs");
+    bindgen::Builder::default()
+        .header(header.to_string_lossy())
+        .clang_arg(format!("-I{}", include.display()))
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .generate()
+        .expect("unable to generate igraph bindings")
+        .write_to_file(&out)
+        .expect("unable to write igraph bindings");
+}
+
+/// Links against the library installed under `prefix`, returning its include directory.
+fn link_prefix(prefix: &Path, statik: bool) -> PathBuf {
+    for lib in ["lib", "lib64"] {
+        let dir = prefix.join(lib);
+        if dir.is_dir() {
+            println!("cargo:rustc-link-search=native={}", dir.display());
+        }
+    }
+    link_igraph(statik);
+    prefix.join("include").join("igraph")
+}
+
+fn probe_pkg_config(statik: bool) -> Option<PathBuf> {
+    let library = pkg_config::Config::new()
+        .atleast_version(IGRAPH_VERSION)
+        .statik(statik)
+        .probe("igraph")
+        .ok()?;
+    if statik {
+        link_cxx_runtime();
+    }
+    library
+        .include_paths
+        .iter()
+        .find(|path| path.join("igraph.h").is_file())
+        .or(library.include_paths.first())
+        .cloned()
+}
+
+fn link_igraph(statik: bool) {
+    if statik {
+        println!("cargo:rustc-link-lib=static=igraph");
+        link_cxx_runtime();
+    } else {
+        println!("cargo:rustc-link-lib=igraph");
+    }
+}
+
+/// Parts of igraph are written in C++, so a static libigraph needs the C++ standard library;
+/// `IGRAPH_CXX_STDLIB` overrides the choice (an empty value links none).
+fn link_cxx_runtime() {
+    let stdlib = env::var("IGRAPH_CXX_STDLIB").ok().unwrap_or_else(|| {
+        let target = env::var("TARGET").unwrap_or_default();
+        if target.contains("msvc") {
+            String::new()
+        } else if target.contains("apple")
+            || target.contains("freebsd")
+            || target.contains("openbsd")
+            || target.contains("android")
+        {
+            "c++".to_string()
+        } else {
+            "stdc++".to_string()
+        }
+    });
+    if !stdlib.is_empty() {
+        println!("cargo:rustc-link-lib={}", stdlib);
+    }
+}
+
+/// Finds the igraph sources: those bundled under `vendor/igraph` or, with the
+/// `vendored-download` feature, the release tarball fetched and extracted into `OUT_DIR`.
+#[cfg(feature = "vendored")]
+fn vendored_sources() -> PathBuf {
+    let bundled = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("vendor")
+        .join("igraph");
+    if bundled.join("CMakeLists.txt").is_file() {
+        println!("cargo:rerun-if-changed={}", bundled.display());
+        return bundled;
+    }
+    if env::var_os("CARGO_FEATURE_VENDORED_DOWNLOAD").is_none() {
+        panic!(
+            "the `vendored` feature needs the igraph {} sources in {}; run `make vendor` first, \
+             or enable the `vendored-download` feature to fetch them during the build",
+            IGRAPH_VERSION,
+            bundled.display()
+        );
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let source = out.join(format!("igraph-{}", IGRAPH_VERSION));
+    if source.join("CMakeLists.txt").is_file() {
+        return source;
+    }
+    let tarball = out.join(format!("igraph-{}.tar.gz", IGRAPH_VERSION));
+    let url = format!(
+        "https://github.com/igraph/igraph/releases/download/{0}/igraph-{0}.tar.gz",
+        IGRAPH_VERSION
+    );
+    run(std::process::Command::new("curl")
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--output",
+        ])
+        .arg(&tarball)
+        .arg(&url));
+    run(std::process::Command::new("tar")
+        .arg("-xzf")
+        .arg(&tarball)
+        .arg("-C")
+        .arg(&out));
+    source
+}
+
+#[cfg(feature = "vendored")]
+fn run(command: &mut std::process::Command) {
+    match command.status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!("{:?} failed with {}", command, status),
+        Err(err) => panic!("cannot run {:?}: {}", command, err),
+    }
+}
+
+/// Builds igraph with cmake. The library is always static: a shared one would live in
+/// `OUT_DIR`, where the dynamic loader of the final binaries cannot find it.
+#[cfg(feature = "vendored")]
+fn build_vendored() -> PathBuf {
+    let source = vendored_sources();
+
+    let on_off = |on: bool| if on { "ON" } else { "OFF" };
+    let feature = |name: &str| env::var_os(format!("CARGO_FEATURE_{}", name)).is_some();
+    let glpk = feature("GLPK");
+    let graphml = feature("GRAPHML");
+    let system_arpack = feature("SYSTEM_ARPACK");
+    let system_blas = feature("SYSTEM_BLAS");
+
+    let prefix = cmake::Config::new(&source)
+        .define("BUILD_SHARED_LIBS", "OFF")
+        .define("BUILD_TESTING", "OFF")
+        .define("IGRAPH_ENABLE_TLS", "ON")
+        .define("IGRAPH_OPENMP_SUPPORT", "OFF")
+        .define("IGRAPH_GLPK_SUPPORT", on_off(glpk))
+        .define("IGRAPH_USE_INTERNAL_GLPK", on_off(glpk))
+        .define("IGRAPH_GRAPHML_SUPPORT", on_off(graphml))
+        .define("IGRAPH_USE_INTERNAL_GMP", "ON")
+        .define("IGRAPH_USE_INTERNAL_PLFIT", "ON")
+        .define("IGRAPH_USE_INTERNAL_ARPACK", on_off(!system_arpack))
+        .define("IGRAPH_USE_INTERNAL_BLAS", on_off(!system_blas))
+        .define("IGRAPH_USE_INTERNAL_LAPACK", on_off(!system_blas))
+        .build();
+
+    let include = link_prefix(&prefix, true);
+    if system_arpack {
+        println!("cargo:rustc-link-lib=arpack");
+    }
+    if system_blas {
+        println!("cargo:rustc-link-lib=lapack");
+        println!("cargo:rustc-link-lib=blas");
+    }
+    if graphml {
+        println!("cargo:rustc-link-lib=xml2");
+    }
+    include
+}
+
+#[cfg(not(feature = "vendored"))]
+fn build_vendored() -> PathBuf {
+    unreachable!("CARGO_FEATURE_VENDORED is set only when the `vendored` feature is enabled")
+}
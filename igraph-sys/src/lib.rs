@@ -0,0 +1,15 @@
+//! Raw FFI bindings to the [igraph](https://igraph.org/c/) C library, as generated by `bindgen`
+//! from `igraph.h`; the safe, idiomatic API lives in the `igraph` crate.
+//!
+//! The `IGRAPH_VERSION_*` constants describe the headers the bindings were generated from,
+//! which may differ from the library linked at runtime: compare them with `igraph_version`.
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(clippy::all)]
+
+#[cfg(not(feature = "bindgen"))]
+include!("../bindings.rs");
+
+#[cfg(feature = "bindgen")]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
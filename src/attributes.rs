@@ -0,0 +1,1390 @@
+//! Graph, vertex and edge attributes stored on the Rust side.
+//!
+//! igraph keeps attributes through an `igraph_attribute_table_t`: callbacks it invokes
+//! whenever a graph is created, copied or grown, and whenever its vertices or edges are
+//! permuted, merged or deleted. [enable_rust_attributes] installs the table implemented
+//! here, which keeps the attributes of each graph in a Rust map behind `igraph_t::attr`,
+//! so they follow the graph through [Graph::try_clone], [Graph::delete_vertices],
+//! [Graph::induced_subgraph], [Graph::permute_vertices] and the rest of the library, and
+//! are visible to igraph functions reading attributes, such as the GraphML writer.
+//!
+//! Every attribute has an [AttributeKind]; vertices and edges that were never given a
+//! value hold the default of the kind (`NaN`, `false`, the empty string or `null`).
+//!
+//! [enable_c_attributes] installs igraph's own C attribute handler instead; the same
+//! `Graph::*_attr` methods then go through the `igraph_cattribute_*` functions.
+//!
+//! The enabled table is per thread and applies to the graphs created afterwards. Each
+//! [Graph] remembers the table it was created with and installs it in igraph whenever it
+//! is used, so graphs created under different tables, or moved from a thread that
+//! enabled another table, can be used side by side.
+
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    ffi::{CStr, CString, c_char, c_int},
+    fmt,
+};
+
+use igraph_sys::{
+    igraph_attribute_combination_query, igraph_attribute_combination_t,
+    igraph_attribute_combination_type_t,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_CONCAT,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_DEFAULT,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_FIRST,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_IGNORE,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_LAST,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MAX,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MEAN,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MEDIAN,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MIN,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_PROD,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_RANDOM,
+    igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_SUM, igraph_attribute_elemtype_t,
+    igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_EDGE,
+    igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_GRAPH,
+    igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_VERTEX, igraph_attribute_record_list_t,
+    igraph_attribute_record_t, igraph_attribute_table_t, igraph_attribute_type_t,
+    igraph_attribute_type_t_IGRAPH_ATTRIBUTE_BOOLEAN,
+    igraph_attribute_type_t_IGRAPH_ATTRIBUTE_NUMERIC,
+    igraph_attribute_type_t_IGRAPH_ATTRIBUTE_STRING, igraph_bool_t, igraph_cattribute_table,
+    igraph_ecount, igraph_error, igraph_error_t, igraph_error_type_t_IGRAPH_EATTRCOMBINE,
+    igraph_error_type_t_IGRAPH_EINVAL, igraph_error_type_t_IGRAPH_FAILURE,
+    igraph_error_type_t_IGRAPH_SUCCESS, igraph_error_type_t_IGRAPH_UNIMPLEMENTED,
+    igraph_es_as_vector, igraph_es_t, igraph_int_t, igraph_rng_default, igraph_rng_get_integer,
+    igraph_set_attribute_table, igraph_strvector_clear, igraph_strvector_get,
+    igraph_strvector_push_back, igraph_strvector_resize, igraph_strvector_set,
+    igraph_strvector_size, igraph_strvector_t, igraph_t, igraph_vcount, igraph_vector_bool_resize,
+    igraph_vector_bool_t, igraph_vector_int_clear, igraph_vector_int_list_get_ptr,
+    igraph_vector_int_list_size, igraph_vector_int_list_t, igraph_vector_int_push_back,
+    igraph_vector_int_t, igraph_vector_resize, igraph_vector_t, igraph_vs_as_vector, igraph_vs_t,
+};
+
+use crate::{
+    EdgeId, Graph, IgraphError, Result, VertexId, cattributes,
+    graph::Directedness,
+    thread,
+    vector::{Vector, as_slice},
+};
+
+/// The type of an attribute, mirroring `igraph_attribute_type_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeKind {
+    Numeric,
+    Boolean,
+    String,
+    /// Arbitrary JSON values, which igraph sees as opaque objects.
+    #[cfg(feature = "json")]
+    Json,
+}
+
+impl AttributeKind {
+    pub(crate) fn raw(self) -> igraph_attribute_type_t {
+        match self {
+            Self::Numeric => igraph_attribute_type_t_IGRAPH_ATTRIBUTE_NUMERIC,
+            Self::Boolean => igraph_attribute_type_t_IGRAPH_ATTRIBUTE_BOOLEAN,
+            Self::String => igraph_attribute_type_t_IGRAPH_ATTRIBUTE_STRING,
+            #[cfg(feature = "json")]
+            Self::Json => igraph_sys::igraph_attribute_type_t_IGRAPH_ATTRIBUTE_OBJECT,
+        }
+    }
+
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn from_raw(raw: igraph_attribute_type_t) -> Option<Self> {
+        match raw {
+            igraph_attribute_type_t_IGRAPH_ATTRIBUTE_NUMERIC => Some(Self::Numeric),
+            igraph_attribute_type_t_IGRAPH_ATTRIBUTE_BOOLEAN => Some(Self::Boolean),
+            igraph_attribute_type_t_IGRAPH_ATTRIBUTE_STRING => Some(Self::String),
+            _ => None,
+        }
+    }
+
+    /// The value held by vertices and edges that were never given one.
+    pub fn default_value(self) -> AttributeValue {
+        match self {
+            Self::Numeric => AttributeValue::Numeric(f64::NAN),
+            Self::Boolean => AttributeValue::Boolean(false),
+            Self::String => AttributeValue::String(String::new()),
+            #[cfg(feature = "json")]
+            Self::Json => AttributeValue::Json(serde_json::Value::Null),
+        }
+    }
+}
+
+/// The value of an attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    Numeric(f64),
+    Boolean(bool),
+    String(String),
+    #[cfg(feature = "json")]
+    Json(serde_json::Value),
+}
+
+impl AttributeValue {
+    pub fn kind(&self) -> AttributeKind {
+        match self {
+            Self::Numeric(_) => AttributeKind::Numeric,
+            Self::Boolean(_) => AttributeKind::Boolean,
+            Self::String(_) => AttributeKind::String,
+            #[cfg(feature = "json")]
+            Self::Json(_) => AttributeKind::Json,
+        }
+    }
+}
+
+impl fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(value) => value.fmt(f),
+            Self::Boolean(value) => value.fmt(f),
+            Self::String(value) => value.fmt(f),
+            #[cfg(feature = "json")]
+            Self::Json(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        Self::Numeric(value)
+    }
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Value> for AttributeValue {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+macro_rules! impl_try_from_value {
+    ($t:ty, $variant:ident) => {
+        /// Fails with the value itself when it has another kind.
+        impl TryFrom<AttributeValue> for $t {
+            type Error = AttributeValue;
+
+            fn try_from(value: AttributeValue) -> std::result::Result<Self, AttributeValue> {
+                match value {
+                    AttributeValue::$variant(value) => Ok(value),
+                    other => Err(other),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_value!(f64, Numeric);
+impl_try_from_value!(bool, Boolean);
+impl_try_from_value!(String, String);
+#[cfg(feature = "json")]
+impl_try_from_value!(serde_json::Value, Json);
+
+/// The values of a vertex or edge attribute, indexed by id.
+#[derive(Debug, Clone)]
+struct Column {
+    kind: AttributeKind,
+    values: Vec<AttributeValue>,
+}
+
+impl Column {
+    fn new(kind: AttributeKind, len: usize) -> Self {
+        Self {
+            kind,
+            values: vec![kind.default_value(); len],
+        }
+    }
+
+    fn get(&self, id: i64) -> AttributeValue {
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| self.values.get(id))
+            .cloned()
+            .unwrap_or_else(|| self.kind.default_value())
+    }
+
+    fn resize(&mut self, len: usize) {
+        self.values.resize(len, self.kind.default_value());
+    }
+
+    /// The column whose `i`-th value is the `ids[i]`-th value of this one.
+    fn select(&self, ids: &[i64]) -> Self {
+        Self {
+            kind: self.kind,
+            values: ids.iter().map(|&id| self.get(id)).collect(),
+        }
+    }
+}
+
+/// The attributes of a graph, owned by its `igraph_t::attr`.
+#[derive(Debug, Clone, Default)]
+struct Attributes {
+    graph: BTreeMap<String, AttributeValue>,
+    vertices: BTreeMap<String, Column>,
+    edges: BTreeMap<String, Column>,
+}
+
+/// Which attributes of a graph an operation reads or writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Scope {
+    Graph,
+    Vertices,
+    Edges,
+}
+
+impl Scope {
+    pub(crate) fn raw(self) -> igraph_attribute_elemtype_t {
+        match self {
+            Self::Graph => igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_GRAPH,
+            Self::Vertices => igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_VERTEX,
+            Self::Edges => igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_EDGE,
+        }
+    }
+}
+
+/// The attribute tables this crate can install.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Table {
+    Rust,
+    C,
+}
+
+thread_local! {
+    /// The table graphs created on this thread get.
+    static ENABLED: Cell<Option<Table>> = const { Cell::new(None) };
+    /// The table this thread last installed in igraph.
+    static INSTALLED: Cell<Option<Table>> = const { Cell::new(None) };
+}
+
+static RUST_TABLE: igraph_attribute_table_t = igraph_attribute_table_t {
+    init: Some(init),
+    destroy: Some(destroy),
+    copy: Some(copy),
+    add_vertices: Some(add_vertices),
+    permute_vertices: Some(permute_vertices),
+    combine_vertices: Some(combine_vertices),
+    add_edges: Some(add_edges),
+    permute_edges: Some(permute_edges),
+    combine_edges: Some(combine_edges),
+    get_info: Some(get_info),
+    has_attr: Some(has_attr),
+    get_type: Some(get_type),
+    get_numeric_graph_attr: Some(get_numeric_graph_attr),
+    get_string_graph_attr: Some(get_string_graph_attr),
+    get_bool_graph_attr: Some(get_bool_graph_attr),
+    get_numeric_vertex_attr: Some(get_numeric_vertex_attr),
+    get_string_vertex_attr: Some(get_string_vertex_attr),
+    get_bool_vertex_attr: Some(get_bool_vertex_attr),
+    get_numeric_edge_attr: Some(get_numeric_edge_attr),
+    get_string_edge_attr: Some(get_string_edge_attr),
+    get_bool_edge_attr: Some(get_bool_edge_attr),
+};
+
+/// Installs `table` in igraph, which runs its callbacks on every graph it creates or
+/// touches. Installing is a pointer assignment, repeated on every call since igraph keeps
+/// a single table for all threads when built without thread-local storage.
+pub(crate) fn install(table: Option<Table>) {
+    let raw = match table {
+        Some(Table::Rust) => &RUST_TABLE,
+        Some(Table::C) => &raw const igraph_cattribute_table,
+        None => std::ptr::null(),
+    };
+    thread::serialized(|| unsafe {
+        igraph_set_attribute_table(raw);
+    });
+    INSTALLED.with(|installed| installed.set(table));
+}
+
+/// The table enabled for graphs created on this thread.
+pub(crate) fn enabled() -> Option<Table> {
+    ENABLED.with(Cell::get)
+}
+
+/// The table this thread last installed, i.e. the one a graph just created by igraph
+/// carries.
+pub(crate) fn installed() -> Option<Table> {
+    INSTALLED.with(Cell::get)
+}
+
+fn enable(table: Option<Table>) {
+    ENABLED.with(|enabled| enabled.set(table));
+}
+
+/// Gives the graphs created from now on in this thread the Rust attribute table; graphs
+/// created earlier keep their table.
+pub fn enable_rust_attributes() {
+    enable(Some(Table::Rust));
+}
+
+/// Gives the graphs created from now on in this thread igraph's built-in C attribute
+/// handler, `igraph_cattribute_table`, which stores numeric, boolean and string
+/// attributes only, and lists names in creation order; graphs created earlier keep their
+/// table.
+pub fn enable_c_attributes() {
+    enable(Some(Table::C));
+}
+
+/// Creates the graphs from now on in this thread without attributes; graphs created
+/// earlier keep their table.
+pub fn disable_attributes() {
+    enable(None);
+}
+
+/// Reports an error from a callback through igraph's error handler, returning `code`.
+macro_rules! fail {
+    ($reason:expr, $code:expr) => {{
+        let reason = CString::new($reason).unwrap_or_default();
+        unsafe {
+            igraph_error(
+                reason.as_ptr(),
+                concat!(file!(), "\0").as_ptr().cast(),
+                line!() as c_int,
+                $code,
+            )
+        }
+    }};
+}
+
+type Callback<T = ()> = std::result::Result<T, igraph_error_t>;
+
+fn code(result: Callback) -> igraph_error_t {
+    match result {
+        Ok(()) => igraph_error_type_t_IGRAPH_SUCCESS,
+        Err(code) => code,
+    }
+}
+
+fn propagate(code: igraph_error_t) -> Callback {
+    if code == igraph_error_type_t_IGRAPH_SUCCESS {
+        Ok(())
+    } else {
+        Err(code)
+    }
+}
+
+fn from_igraph(error: IgraphError) -> igraph_error_t {
+    error.code().unwrap_or(igraph_error_type_t_IGRAPH_FAILURE)
+}
+
+// Graphs created before the table was installed have no attributes.
+static NO_ATTRIBUTES: Attributes = Attributes {
+    graph: BTreeMap::new(),
+    vertices: BTreeMap::new(),
+    edges: BTreeMap::new(),
+};
+
+unsafe fn attributes<'g>(graph: *const igraph_t) -> &'g Attributes {
+    let attr = unsafe { (*graph).attr } as *const Attributes;
+    if attr.is_null() {
+        &NO_ATTRIBUTES
+    } else {
+        unsafe { &*attr }
+    }
+}
+
+unsafe fn attributes_mut<'g>(graph: *mut igraph_t) -> &'g mut Attributes {
+    unsafe {
+        if (*graph).attr.is_null() {
+            (*graph).attr = Box::into_raw(Box::<Attributes>::default()).cast();
+        }
+        &mut *((*graph).attr as *mut Attributes)
+    }
+}
+
+unsafe fn name<'n>(name: *const c_char) -> std::borrow::Cow<'n, str> {
+    unsafe { CStr::from_ptr(name) }.to_string_lossy()
+}
+
+/// The records passed by igraph when creating a graph or adding vertices and edges.
+unsafe fn records<'r>(
+    list: *const igraph_attribute_record_list_t,
+) -> &'r [igraph_attribute_record_t] {
+    if list.is_null() || unsafe { (*list).stor_begin }.is_null() {
+        return &[];
+    }
+    unsafe {
+        let begin = (*list).stor_begin;
+        std::slice::from_raw_parts(begin, (*list).end.offset_from(begin) as usize)
+    }
+}
+
+/// The strings held by `strings`.
+pub(crate) unsafe fn strings(strings: *const igraph_strvector_t) -> Vec<String> {
+    let len = unsafe { igraph_strvector_size(strings) };
+    (0..len)
+        .map(|i| unsafe { name(igraph_strvector_get(strings, i)) }.into_owned())
+        .collect()
+}
+
+/// The values held by a record, which must have `len` of them.
+unsafe fn record_column(record: &igraph_attribute_record_t, len: usize) -> Callback<Column> {
+    #[allow(non_upper_case_globals)]
+    let mut column = match record.type_ {
+        igraph_attribute_type_t_IGRAPH_ATTRIBUTE_NUMERIC => Column {
+            kind: AttributeKind::Numeric,
+            values: as_slice::<f64>(unsafe { &*record.value.as_vector })
+                .iter()
+                .map(|&value| value.into())
+                .collect(),
+        },
+        igraph_attribute_type_t_IGRAPH_ATTRIBUTE_BOOLEAN => Column {
+            kind: AttributeKind::Boolean,
+            values: as_slice::<bool>(unsafe { &*record.value.as_vector_bool })
+                .iter()
+                .map(|&value| value.into())
+                .collect(),
+        },
+        igraph_attribute_type_t_IGRAPH_ATTRIBUTE_STRING => Column {
+            kind: AttributeKind::String,
+            values: unsafe { strings(record.value.as_strvector) }
+                .into_iter()
+                .map(AttributeValue::from)
+                .collect(),
+        },
+        _ => {
+            return Err(fail!(
+                format!("attribute `{}` has an unsupported type", unsafe {
+                    name(record.name)
+                }),
+                igraph_error_type_t_IGRAPH_UNIMPLEMENTED
+            ));
+        }
+    };
+    column.resize(len);
+    Ok(column)
+}
+
+/// Appends `added` vertices or edges to `columns`, taking their values from `list`.
+unsafe fn add_elements(
+    columns: &mut BTreeMap<String, Column>,
+    total: usize,
+    added: usize,
+    list: *const igraph_attribute_record_list_t,
+) -> Callback {
+    let previous = total - added;
+    for column in columns.values_mut() {
+        column.resize(previous);
+    }
+    for record in unsafe { records(list) } {
+        let mut values = unsafe { record_column(record, added) }?;
+        let name = unsafe { name(record.name) };
+        let column = columns
+            .entry(name.to_string())
+            .or_insert_with(|| Column::new(values.kind, previous));
+        if column.kind != values.kind {
+            return Err(fail!(
+                format!(
+                    "attribute `{name}` is {:?}, not {:?}",
+                    column.kind, values.kind
+                ),
+                igraph_error_type_t_IGRAPH_EINVAL
+            ));
+        }
+        column.values.append(&mut values.values);
+    }
+    for column in columns.values_mut() {
+        column.resize(total);
+    }
+    Ok(())
+}
+
+unsafe extern "C" fn init(
+    graph: *mut igraph_t,
+    attr: *const igraph_attribute_record_list_t,
+) -> igraph_error_t {
+    let mut attributes = Attributes::default();
+    for record in unsafe { records(attr) } {
+        let column = match unsafe { record_column(record, 1) } {
+            Ok(column) => column,
+            Err(code) => return code,
+        };
+        let value = column.get(0);
+        attributes
+            .graph
+            .insert(unsafe { name(record.name) }.into_owned(), value);
+    }
+    unsafe {
+        (*graph).attr = Box::into_raw(Box::new(attributes)).cast();
+    }
+    igraph_error_type_t_IGRAPH_SUCCESS
+}
+
+unsafe extern "C" fn destroy(graph: *mut igraph_t) {
+    unsafe {
+        let attr = (*graph).attr as *mut Attributes;
+        if !attr.is_null() {
+            drop(Box::from_raw(attr));
+            (*graph).attr = std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn copy(
+    to: *mut igraph_t,
+    from: *const igraph_t,
+    ga: igraph_bool_t,
+    va: igraph_bool_t,
+    ea: igraph_bool_t,
+) -> igraph_error_t {
+    let from = unsafe { attributes(from) };
+    let attributes = Attributes {
+        graph: if ga {
+            from.graph.clone()
+        } else {
+            BTreeMap::new()
+        },
+        vertices: if va {
+            from.vertices.clone()
+        } else {
+            BTreeMap::new()
+        },
+        edges: if ea {
+            from.edges.clone()
+        } else {
+            BTreeMap::new()
+        },
+    };
+    unsafe {
+        (*to).attr = Box::into_raw(Box::new(attributes)).cast();
+    }
+    igraph_error_type_t_IGRAPH_SUCCESS
+}
+
+unsafe extern "C" fn add_vertices(
+    graph: *mut igraph_t,
+    nv: igraph_int_t,
+    attr: *const igraph_attribute_record_list_t,
+) -> igraph_error_t {
+    let total = unsafe { igraph_vcount(graph) } as usize;
+    let vertices = unsafe { &mut attributes_mut(graph).vertices };
+    code(unsafe { add_elements(vertices, total, nv as usize, attr) })
+}
+
+unsafe extern "C" fn add_edges(
+    graph: *mut igraph_t,
+    edges: *const igraph_vector_int_t,
+    attr: *const igraph_attribute_record_list_t,
+) -> igraph_error_t {
+    let total = unsafe { igraph_ecount(graph) } as usize;
+    let added = as_slice::<i64>(unsafe { &*edges }).len() / 2;
+    let columns = unsafe { &mut attributes_mut(graph).edges };
+    code(unsafe { add_elements(columns, total, added, attr) })
+}
+
+/// The columns whose `i`-th value is the `idx[i]`-th value of `columns`.
+fn permute(
+    columns: &BTreeMap<String, Column>,
+    idx: *const igraph_vector_int_t,
+) -> BTreeMap<String, Column> {
+    let idx = as_slice::<i64>(unsafe { &*idx });
+    columns
+        .iter()
+        .map(|(name, column)| (name.clone(), column.select(idx)))
+        .collect()
+}
+
+// `graph` and `newgraph` may be the same graph, so the permuted columns are built
+// before borrowing `newgraph` mutably.
+unsafe extern "C" fn permute_vertices(
+    graph: *const igraph_t,
+    newgraph: *mut igraph_t,
+    idx: *const igraph_vector_int_t,
+) -> igraph_error_t {
+    let vertices = permute(unsafe { &attributes(graph).vertices }, idx);
+    unsafe { attributes_mut(newgraph) }.vertices = vertices;
+    igraph_error_type_t_IGRAPH_SUCCESS
+}
+
+unsafe extern "C" fn permute_edges(
+    graph: *const igraph_t,
+    newgraph: *mut igraph_t,
+    idx: *const igraph_vector_int_t,
+) -> igraph_error_t {
+    let edges = permute(unsafe { &attributes(graph).edges }, idx);
+    unsafe { attributes_mut(newgraph) }.edges = edges;
+    igraph_error_type_t_IGRAPH_SUCCESS
+}
+
+/// Merges the values of each group of ids as `how` prescribes; `None` drops the attribute.
+fn merge(
+    column: &Column,
+    groups: &[&[i64]],
+    how: igraph_attribute_combination_type_t,
+) -> std::result::Result<Option<Column>, &'static str> {
+    let kind = column.kind;
+    let numbers = |ids: &[i64]| -> Vec<f64> {
+        ids.iter()
+            .map(|&id| match column.get(id) {
+                AttributeValue::Numeric(value) => value,
+                AttributeValue::Boolean(value) => f64::from(u8::from(value)),
+                _ => f64::NAN,
+            })
+            .collect()
+    };
+    let reduce =
+        |reducer: fn(Vec<f64>) -> f64| -> std::result::Result<Vec<AttributeValue>, &'static str> {
+            match kind {
+                AttributeKind::Numeric => Ok(groups
+                    .iter()
+                    .map(|ids| reducer(numbers(ids)).into())
+                    .collect()),
+                // Booleans combine as their 0/1 values, rounded back: sum and max give "any",
+                // product and min "all", mean and median "most".
+                AttributeKind::Boolean => Ok(groups
+                    .iter()
+                    .map(|ids| (reducer(numbers(ids)) >= 0.5).into())
+                    .collect()),
+                _ => Err("this combination needs a numeric or boolean attribute"),
+            }
+        };
+    #[allow(non_upper_case_globals)]
+    let values = match how {
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_IGNORE
+        | igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_DEFAULT => return Ok(None),
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_FIRST => groups
+            .iter()
+            .map(|ids| {
+                ids.first()
+                    .map_or(kind.default_value(), |&id| column.get(id))
+            })
+            .collect(),
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_LAST => groups
+            .iter()
+            .map(|ids| {
+                ids.last()
+                    .map_or(kind.default_value(), |&id| column.get(id))
+            })
+            .collect(),
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_RANDOM => groups
+            .iter()
+            .map(|ids| match ids.len() {
+                0 => kind.default_value(),
+                len => {
+                    let pick =
+                        unsafe { igraph_rng_get_integer(igraph_rng_default(), 0, len as i64 - 1) };
+                    column.get(ids[pick as usize])
+                }
+            })
+            .collect(),
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_SUM => {
+            reduce(|values| values.iter().sum())?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_PROD => {
+            reduce(|values| values.iter().product())?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MIN => {
+            reduce(|values| values.into_iter().reduce(f64::min).unwrap_or(f64::NAN))?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MAX => {
+            reduce(|values| values.into_iter().reduce(f64::max).unwrap_or(f64::NAN))?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MEAN => {
+            reduce(|values| values.iter().sum::<f64>() / values.len() as f64)?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_MEDIAN => {
+            reduce(|mut values| {
+                values.sort_by(f64::total_cmp);
+                match values.len() {
+                    0 => f64::NAN,
+                    len if len % 2 == 1 => values[len / 2],
+                    len => (values[len / 2 - 1] + values[len / 2]) / 2.0,
+                }
+            })?
+        }
+        igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_CONCAT => match kind {
+            AttributeKind::String => groups
+                .iter()
+                .map(|ids| {
+                    ids.iter()
+                        .map(|&id| column.get(id).to_string())
+                        .collect::<String>()
+                        .into()
+                })
+                .collect(),
+            _ => return Err("only string attributes can be concatenated"),
+        },
+        _ => return Err("only the built-in combinations are supported"),
+    };
+    Ok(Some(Column { kind, values }))
+}
+
+unsafe fn combine(
+    columns: &BTreeMap<String, Column>,
+    merges: *const igraph_vector_int_list_t,
+    comb: *const igraph_attribute_combination_t,
+) -> Callback<BTreeMap<String, Column>> {
+    let groups: Vec<&[i64]> = (0..unsafe { igraph_vector_int_list_size(merges) })
+        .map(|i| as_slice::<i64>(unsafe { &*igraph_vector_int_list_get_ptr(merges, i) }))
+        .collect();
+    let mut combined = BTreeMap::new();
+    for (name, column) in columns {
+        let c_name = CString::new(name.as_str()).unwrap_or_default();
+        let mut how = igraph_attribute_combination_type_t_IGRAPH_ATTRIBUTE_COMBINE_DEFAULT;
+        let mut func = None;
+        propagate(unsafe {
+            igraph_attribute_combination_query(comb, c_name.as_ptr(), &mut how, &mut func)
+        })?;
+        match merge(column, &groups, how) {
+            Ok(Some(column)) => {
+                combined.insert(name.clone(), column);
+            }
+            Ok(None) => {}
+            Err(reason) => {
+                return Err(fail!(
+                    format!("cannot combine attribute `{name}`: {reason}"),
+                    igraph_error_type_t_IGRAPH_EATTRCOMBINE
+                ));
+            }
+        }
+    }
+    Ok(combined)
+}
+
+unsafe extern "C" fn combine_vertices(
+    graph: *const igraph_t,
+    newgraph: *mut igraph_t,
+    merges: *const igraph_vector_int_list_t,
+    comb: *const igraph_attribute_combination_t,
+) -> igraph_error_t {
+    match unsafe { combine(&attributes(graph).vertices, merges, comb) } {
+        Ok(vertices) => {
+            unsafe { attributes_mut(newgraph) }.vertices = vertices;
+            igraph_error_type_t_IGRAPH_SUCCESS
+        }
+        Err(code) => code,
+    }
+}
+
+unsafe extern "C" fn combine_edges(
+    graph: *const igraph_t,
+    newgraph: *mut igraph_t,
+    merges: *const igraph_vector_int_list_t,
+    comb: *const igraph_attribute_combination_t,
+) -> igraph_error_t {
+    match unsafe { combine(&attributes(graph).edges, merges, comb) } {
+        Ok(edges) => {
+            unsafe { attributes_mut(newgraph) }.edges = edges;
+            igraph_error_type_t_IGRAPH_SUCCESS
+        }
+        Err(code) => code,
+    }
+}
+
+/// Fills `names` and `types`, either of which may be null.
+unsafe fn info<'a>(
+    entries: impl Iterator<Item = (&'a String, AttributeKind)>,
+    names: *mut igraph_strvector_t,
+    types: *mut igraph_vector_int_t,
+) -> Callback {
+    unsafe {
+        if !names.is_null() {
+            igraph_strvector_clear(names);
+        }
+        if !types.is_null() {
+            igraph_vector_int_clear(types);
+        }
+        for (name, kind) in entries {
+            if !names.is_null() {
+                let name = CString::new(name.as_str()).unwrap_or_default();
+                propagate(igraph_strvector_push_back(names, name.as_ptr()))?;
+            }
+            if !types.is_null() {
+                propagate(igraph_vector_int_push_back(types, kind.raw() as i64))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+unsafe extern "C" fn get_info(
+    graph: *const igraph_t,
+    gnames: *mut igraph_strvector_t,
+    gtypes: *mut igraph_vector_int_t,
+    vnames: *mut igraph_strvector_t,
+    vtypes: *mut igraph_vector_int_t,
+    enames: *mut igraph_strvector_t,
+    etypes: *mut igraph_vector_int_t,
+) -> igraph_error_t {
+    let attributes = unsafe { attributes(graph) };
+    code(unsafe {
+        info(
+            attributes
+                .graph
+                .iter()
+                .map(|(name, value)| (name, value.kind())),
+            gnames,
+            gtypes,
+        )
+        .and_then(|()| {
+            info(
+                attributes
+                    .vertices
+                    .iter()
+                    .map(|(name, column)| (name, column.kind)),
+                vnames,
+                vtypes,
+            )
+        })
+        .and_then(|()| {
+            info(
+                attributes
+                    .edges
+                    .iter()
+                    .map(|(name, column)| (name, column.kind)),
+                enames,
+                etypes,
+            )
+        })
+    })
+}
+
+/// The kind of the attribute `name` of the given element type, if any.
+unsafe fn kind_of(
+    graph: *const igraph_t,
+    elemtype: igraph_attribute_elemtype_t,
+    name: &str,
+) -> Option<AttributeKind> {
+    let attributes = unsafe { attributes(graph) };
+    #[allow(non_upper_case_globals)]
+    match elemtype {
+        igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_GRAPH => {
+            attributes.graph.get(name).map(AttributeValue::kind)
+        }
+        igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_VERTEX => {
+            attributes.vertices.get(name).map(|column| column.kind)
+        }
+        igraph_attribute_elemtype_t_IGRAPH_ATTRIBUTE_EDGE => {
+            attributes.edges.get(name).map(|column| column.kind)
+        }
+        _ => None,
+    }
+}
+
+unsafe extern "C" fn has_attr(
+    graph: *const igraph_t,
+    type_: igraph_attribute_elemtype_t,
+    name: *const c_char,
+) -> igraph_bool_t {
+    unsafe { kind_of(graph, type_, &self::name(name)) }.is_some()
+}
+
+unsafe extern "C" fn get_type(
+    graph: *const igraph_t,
+    type_: *mut igraph_attribute_type_t,
+    elemtype: igraph_attribute_elemtype_t,
+    name: *const c_char,
+) -> igraph_error_t {
+    let name = unsafe { self::name(name) };
+    match unsafe { kind_of(graph, elemtype, &name) } {
+        Some(kind) => {
+            unsafe { *type_ = kind.raw() };
+            igraph_error_type_t_IGRAPH_SUCCESS
+        }
+        None => fail!(
+            format!("there is no attribute `{name}`"),
+            igraph_error_type_t_IGRAPH_EINVAL
+        ),
+    }
+}
+
+/// Which values a getter callback asks for.
+enum Elements {
+    Graph,
+    Vertices(igraph_vs_t),
+    Edges(igraph_es_t),
+}
+
+/// The values of the attribute `name` for `elements`, which must be of the given kind.
+unsafe fn values(
+    graph: *const igraph_t,
+    name: *const c_char,
+    elements: Elements,
+    kind: AttributeKind,
+) -> Callback<Vec<AttributeValue>> {
+    let name = unsafe { self::name(name) };
+    let attributes = unsafe { attributes(graph) };
+    let absent = || {
+        fail!(
+            format!("there is no attribute `{name}`"),
+            igraph_error_type_t_IGRAPH_EINVAL
+        )
+    };
+    let (found, values) = match elements {
+        Elements::Graph => {
+            let value = attributes.graph.get(name.as_ref()).ok_or_else(absent)?;
+            (value.kind(), vec![value.clone()])
+        }
+        Elements::Vertices(vs) => {
+            let column = attributes.vertices.get(name.as_ref()).ok_or_else(absent)?;
+            let mut ids = Vector::<i64>::new().map_err(from_igraph)?;
+            propagate(unsafe { igraph_vs_as_vector(graph, vs, ids.as_mut_ptr()) })?;
+            (column.kind, ids.iter().map(|&id| column.get(id)).collect())
+        }
+        Elements::Edges(es) => {
+            let column = attributes.edges.get(name.as_ref()).ok_or_else(absent)?;
+            let mut ids = Vector::<i64>::new().map_err(from_igraph)?;
+            propagate(unsafe { igraph_es_as_vector(graph, es, ids.as_mut_ptr()) })?;
+            (column.kind, ids.iter().map(|&id| column.get(id)).collect())
+        }
+    };
+    if found != kind {
+        return Err(fail!(
+            format!("attribute `{name}` is {found:?}, not {kind:?}"),
+            igraph_error_type_t_IGRAPH_EINVAL
+        ));
+    }
+    Ok(values)
+}
+
+unsafe fn numeric(
+    graph: *const igraph_t,
+    name: *const c_char,
+    elements: Elements,
+    value: *mut igraph_vector_t,
+) -> igraph_error_t {
+    code((|| {
+        let values = unsafe { values(graph, name, elements, AttributeKind::Numeric) }?;
+        propagate(unsafe { igraph_vector_resize(value, values.len() as i64) })?;
+        for (i, v) in values.into_iter().enumerate() {
+            if let AttributeValue::Numeric(v) = v {
+                unsafe { *(*value).stor_begin.add(i) = v };
+            }
+        }
+        Ok(())
+    })())
+}
+
+unsafe fn boolean(
+    graph: *const igraph_t,
+    name: *const c_char,
+    elements: Elements,
+    value: *mut igraph_vector_bool_t,
+) -> igraph_error_t {
+    code((|| {
+        let values = unsafe { values(graph, name, elements, AttributeKind::Boolean) }?;
+        propagate(unsafe { igraph_vector_bool_resize(value, values.len() as i64) })?;
+        for (i, v) in values.into_iter().enumerate() {
+            if let AttributeValue::Boolean(v) = v {
+                unsafe { *(*value).stor_begin.add(i) = v };
+            }
+        }
+        Ok(())
+    })())
+}
+
+unsafe fn string(
+    graph: *const igraph_t,
+    name: *const c_char,
+    elements: Elements,
+    value: *mut igraph_strvector_t,
+) -> igraph_error_t {
+    code((|| {
+        let values = unsafe { values(graph, name, elements, AttributeKind::String) }?;
+        propagate(unsafe { igraph_strvector_resize(value, values.len() as i64) })?;
+        for (i, v) in values.into_iter().enumerate() {
+            let v = CString::new(v.to_string()).unwrap_or_default();
+            propagate(unsafe { igraph_strvector_set(value, i as i64, v.as_ptr()) })?;
+        }
+        Ok(())
+    })())
+}
+
+unsafe extern "C" fn get_numeric_graph_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    value: *mut igraph_vector_t,
+) -> igraph_error_t {
+    unsafe { numeric(graph, name, Elements::Graph, value) }
+}
+
+unsafe extern "C" fn get_string_graph_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    value: *mut igraph_strvector_t,
+) -> igraph_error_t {
+    unsafe { string(graph, name, Elements::Graph, value) }
+}
+
+unsafe extern "C" fn get_bool_graph_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    value: *mut igraph_vector_bool_t,
+) -> igraph_error_t {
+    unsafe { boolean(graph, name, Elements::Graph, value) }
+}
+
+unsafe extern "C" fn get_numeric_vertex_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    vs: igraph_vs_t,
+    value: *mut igraph_vector_t,
+) -> igraph_error_t {
+    unsafe { numeric(graph, name, Elements::Vertices(vs), value) }
+}
+
+unsafe extern "C" fn get_string_vertex_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    vs: igraph_vs_t,
+    value: *mut igraph_strvector_t,
+) -> igraph_error_t {
+    unsafe { string(graph, name, Elements::Vertices(vs), value) }
+}
+
+unsafe extern "C" fn get_bool_vertex_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    vs: igraph_vs_t,
+    value: *mut igraph_vector_bool_t,
+) -> igraph_error_t {
+    unsafe { boolean(graph, name, Elements::Vertices(vs), value) }
+}
+
+unsafe extern "C" fn get_numeric_edge_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    es: igraph_es_t,
+    value: *mut igraph_vector_t,
+) -> igraph_error_t {
+    unsafe { numeric(graph, name, Elements::Edges(es), value) }
+}
+
+unsafe extern "C" fn get_string_edge_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    es: igraph_es_t,
+    value: *mut igraph_strvector_t,
+) -> igraph_error_t {
+    unsafe { string(graph, name, Elements::Edges(es), value) }
+}
+
+unsafe extern "C" fn get_bool_edge_attr(
+    graph: *const igraph_t,
+    name: *const c_char,
+    es: igraph_es_t,
+    value: *mut igraph_vector_bool_t,
+) -> igraph_error_t {
+    unsafe { boolean(graph, name, Elements::Edges(es), value) }
+}
+
+/// Converts `value` to the requested type, failing if it has another kind.
+fn typed<T: TryFrom<AttributeValue>>(name: &str, value: AttributeValue) -> Result<T> {
+    let kind = value.kind();
+    T::try_from(value)
+        .map_err(|_| IgraphError::invalid_value(format!("attribute `{name}` is {kind:?}")))
+}
+
+fn c_name(name: &str) -> Result<CString> {
+    CString::new(name).map_err(|_| {
+        IgraphError::invalid_value(format!("attribute name `{name}` contains a NUL byte"))
+    })
+}
+
+fn missing(name: &str) -> IgraphError {
+    IgraphError::invalid_value(format!("there is no attribute `{name}`"))
+}
+
+impl Attributes {
+    fn get(&self, scope: Scope, name: &str, id: i64) -> Option<AttributeValue> {
+        match scope {
+            Scope::Graph => self.graph.get(name).cloned(),
+            Scope::Vertices => self.vertices.get(name).map(|column| column.get(id)),
+            Scope::Edges => self.edges.get(name).map(|column| column.get(id)),
+        }
+    }
+
+    /// Sets the value of element `id` out of `len`, creating the column if needed.
+    fn set(
+        &mut self,
+        scope: Scope,
+        name: &str,
+        id: i64,
+        len: usize,
+        value: AttributeValue,
+    ) -> Result<()> {
+        let columns = match scope {
+            Scope::Graph => {
+                self.graph.insert(name.to_string(), value);
+                return Ok(());
+            }
+            Scope::Vertices => &mut self.vertices,
+            Scope::Edges => &mut self.edges,
+        };
+        let column = columns
+            .entry(name.to_string())
+            .or_insert_with(|| Column::new(value.kind(), len));
+        if column.kind != value.kind() {
+            return Err(IgraphError::invalid_value(format!(
+                "attribute `{name}` is {:?}, not {:?}",
+                column.kind,
+                value.kind()
+            )));
+        }
+        column.resize(len);
+        column.values[id as usize] = value;
+        Ok(())
+    }
+
+    fn remove(&mut self, scope: Scope, name: &str) -> bool {
+        match scope {
+            Scope::Graph => self.graph.remove(name).is_some(),
+            Scope::Vertices => self.vertices.remove(name).is_some(),
+            Scope::Edges => self.edges.remove(name).is_some(),
+        }
+    }
+
+    fn names(&self, scope: Scope) -> Vec<String> {
+        match scope {
+            Scope::Graph => self.graph.keys().cloned().collect(),
+            Scope::Vertices => self.vertices.keys().cloned().collect(),
+            Scope::Edges => self.edges.keys().cloned().collect(),
+        }
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    /// The table holding the attributes of this graph.
+    fn attribute_table(&self) -> Result<Table> {
+        self.table.ok_or_else(|| {
+            IgraphError::invalid_value("no attribute table was enabled when the graph was created")
+        })
+    }
+
+    fn attr(&self, scope: Scope, name: &str, id: i64) -> Result<AttributeValue> {
+        let table = self.attribute_table()?;
+        let name_c = c_name(name)?;
+        let value = thread::serialized(|| match table {
+            Table::Rust => unsafe { attributes(self.as_ptr()) }.get(scope, name, id),
+            Table::C => cattributes::get(self, scope, &name_c, id),
+        });
+        value.ok_or_else(|| missing(name))
+    }
+
+    fn set_attr(&mut self, scope: Scope, name: &str, id: i64, value: AttributeValue) -> Result<()> {
+        let name = c_name(name)?;
+        let table = self.attribute_table()?;
+        thread::serialized(|| match table {
+            Table::Rust => {
+                let len = match scope {
+                    Scope::Graph => 1,
+                    Scope::Vertices => self.num_vertices(),
+                    Scope::Edges => self.num_edges(),
+                };
+                let name = name.to_str().expect("the name came from a str");
+                unsafe { attributes_mut(self.as_mut_ptr()) }.set(scope, name, id, len, value)
+            }
+            Table::C => cattributes::set(self, scope, &name, id, value),
+        })
+    }
+
+    fn remove_attr(&mut self, scope: Scope, name: &str) -> Result<()> {
+        let table = self.attribute_table()?;
+        let name_c = c_name(name)?;
+        let removed = thread::serialized(|| match table {
+            Table::Rust => unsafe { attributes_mut(self.as_mut_ptr()) }.remove(scope, name),
+            Table::C => cattributes::remove(self, scope, &name_c),
+        });
+        if removed { Ok(()) } else { Err(missing(name)) }
+    }
+
+    fn attr_names(&self, scope: Scope) -> Result<Vec<String>> {
+        let table = self.attribute_table()?;
+        thread::serialized(|| match table {
+            Table::Rust => Ok(unsafe { attributes(self.as_ptr()) }.names(scope)),
+            Table::C => cattributes::names(self, scope),
+        })
+    }
+
+    /// The graph attribute `name`, converted to `T`.
+    pub fn graph_attr<T: TryFrom<AttributeValue>>(&self, name: &str) -> Result<T> {
+        typed(name, self.attr(Scope::Graph, name, 0)?)
+    }
+
+    pub fn set_graph_attr(&mut self, name: &str, value: impl Into<AttributeValue>) -> Result<()> {
+        self.set_attr(Scope::Graph, name, 0, value.into())
+    }
+
+    pub fn remove_graph_attr(&mut self, name: &str) -> Result<()> {
+        self.remove_attr(Scope::Graph, name)
+    }
+
+    pub fn graph_attr_names(&self) -> Result<Vec<String>> {
+        self.attr_names(Scope::Graph)
+    }
+
+    /// The vertex attribute `name` of `vertex`, converted to `T`.
+    pub fn vertex_attr<T: TryFrom<AttributeValue>>(
+        &self,
+        name: &str,
+        vertex: impl Into<VertexId>,
+    ) -> Result<T> {
+        let vertex = self.check_vertex(vertex.into())?.get();
+        typed(name, self.attr(Scope::Vertices, name, vertex)?)
+    }
+
+    /// Sets the vertex attribute `name` of `vertex`; the other vertices get the default value of
+    /// its kind if the attribute did not exist.
+    pub fn set_vertex_attr(
+        &mut self,
+        name: &str,
+        vertex: impl Into<VertexId>,
+        value: impl Into<AttributeValue>,
+    ) -> Result<()> {
+        let vertex = self.check_vertex(vertex.into())?.get();
+        self.set_attr(Scope::Vertices, name, vertex, value.into())
+    }
+
+    pub fn remove_vertex_attr(&mut self, name: &str) -> Result<()> {
+        self.remove_attr(Scope::Vertices, name)
+    }
+
+    pub fn vertex_attr_names(&self) -> Result<Vec<String>> {
+        self.attr_names(Scope::Vertices)
+    }
+
+    /// The edge attribute `name` of `edge`, converted to `T`.
+    pub fn edge_attr<T: TryFrom<AttributeValue>>(
+        &self,
+        name: &str,
+        edge: impl Into<EdgeId>,
+    ) -> Result<T> {
+        let edge = self.check_edge(edge.into())?.get();
+        typed(name, self.attr(Scope::Edges, name, edge)?)
+    }
+
+    /// Sets the edge attribute `name` of `edge`; the other edges get the default value of
+    /// its kind if the attribute did not exist.
+    pub fn set_edge_attr(
+        &mut self,
+        name: &str,
+        edge: impl Into<EdgeId>,
+        value: impl Into<AttributeValue>,
+    ) -> Result<()> {
+        let edge = self.check_edge(edge.into())?.get();
+        self.set_attr(Scope::Edges, name, edge, value.into())
+    }
+
+    pub fn remove_edge_attr(&mut self, name: &str) -> Result<()> {
+        self.remove_attr(Scope::Edges, name)
+    }
+
+    pub fn edge_attr_names(&self) -> Result<Vec<String>> {
+        self.attr_names(Scope::Edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdgeSelector, VertexSelector};
+
+    fn named_path() -> Graph {
+        Graph::setup().unwrap();
+        enable_rust_attributes();
+        let mut graph = Graph::new(3, false).unwrap();
+        graph.add_edges_from_slice(&[(0, 1), (1, 2)]).unwrap();
+        for (v, name) in ["a", "b", "c"].into_iter().enumerate() {
+            graph.set_vertex_attr("name", v as u32, name).unwrap();
+        }
+        graph.set_edge_attr("weight", 1, 2.5).unwrap();
+        graph.set_graph_attr("title", "path").unwrap();
+        graph
+    }
+
+    fn names(graph: &Graph) -> Vec<String> {
+        (0..graph.num_vertices() as u32)
+            .map(|v| graph.vertex_attr("name", v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_typed_attributes() {
+        let mut graph = named_path();
+        assert_eq!(graph.vertex_attr::<String>("name", 1).unwrap(), "b");
+        assert!(graph.edge_attr::<f64>("weight", 0).unwrap().is_nan());
+        assert_eq!(graph.edge_attr::<f64>("weight", 1).unwrap(), 2.5);
+        assert!(graph.vertex_attr::<f64>("name", 1).is_err());
+        assert!(graph.set_edge_attr("weight", 0, true).is_err());
+        assert!(graph.vertex_attr::<String>("name", 3).is_err());
+
+        graph.add_vertices(1).unwrap();
+        assert_eq!(graph.vertex_attr::<String>("name", 3).unwrap(), "");
+
+        graph.remove_graph_attr("title").unwrap();
+        assert!(graph.graph_attr_names().unwrap().is_empty());
+        assert_eq!(graph.vertex_attr_names().unwrap(), vec!["name"]);
+    }
+
+    #[test]
+    fn test_attributes_follow_the_graph() {
+        let graph = named_path();
+        let copy = graph.clone();
+        assert_eq!(copy.graph_attr::<String>("title").unwrap(), "path");
+        assert_eq!(names(&copy), vec!["a", "b", "c"]);
+
+        let permuted = graph
+            .permute_vertices(&[2.into(), 0.into(), 1.into()])
+            .unwrap();
+        assert_eq!(names(&permuted), vec!["b", "c", "a"]);
+
+        let subgraph = graph
+            .induced_subgraph(VertexSelector::List(&[1.into(), 2.into()]))
+            .unwrap();
+        assert_eq!(names(&subgraph), vec!["b", "c"]);
+        assert_eq!(subgraph.edge_attr::<f64>("weight", 0).unwrap(), 2.5);
+
+        let mut deleted = graph.clone();
+        deleted
+            .delete_vertices(VertexSelector::Single(0.into()))
+            .unwrap();
+        assert_eq!(names(&deleted), vec!["b", "c"]);
+        deleted
+            .delete_edges(EdgeSelector::Single(0.into()))
+            .unwrap();
+        assert_eq!(deleted.num_edges(), 0);
+    }
+
+    #[test]
+    fn test_graphs_keep_their_table() {
+        let rust = named_path();
+        enable_c_attributes();
+        let mut c = Graph::new(2, false).unwrap();
+        c.set_vertex_attr("name", 1, "y").unwrap();
+        disable_attributes();
+        let plain = Graph::new(1, false).unwrap();
+
+        assert_eq!(names(&rust), vec!["a", "b", "c"]);
+        assert_eq!(c.vertex_attr::<String>("name", 1).unwrap(), "y");
+        assert!(plain.vertex_attr_names().is_err());
+        assert_eq!(names(&rust.clone()), vec!["a", "b", "c"]);
+
+        let rust = std::thread::spawn(move || {
+            enable_c_attributes();
+            let mut rust = rust;
+            rust.add_vertices(1).unwrap();
+            assert_eq!(names(&rust), vec!["a", "b", "c", ""]);
+            rust
+        })
+        .join()
+        .unwrap();
+        assert_eq!(rust.num_vertices(), 4);
+    }
+}
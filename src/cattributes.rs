@@ -0,0 +1,231 @@
+//! Attribute access through igraph's built-in C attribute handler, used by the
+//! `Graph::*_attr` methods once [enable_c_attributes](crate::attributes::enable_c_attributes)
+//! installed it.
+
+use std::{
+    ffi::{CStr, CString, c_char},
+    mem,
+};
+
+use igraph_sys::{
+    igraph_attribute_type_t_IGRAPH_ATTRIBUTE_UNSPECIFIED, igraph_cattribute_EAB,
+    igraph_cattribute_EAB_set, igraph_cattribute_EAN, igraph_cattribute_EAN_set,
+    igraph_cattribute_EAS, igraph_cattribute_EAS_set, igraph_cattribute_GAB,
+    igraph_cattribute_GAB_set, igraph_cattribute_GAN, igraph_cattribute_GAN_set,
+    igraph_cattribute_GAS, igraph_cattribute_GAS_set, igraph_cattribute_VAB,
+    igraph_cattribute_VAB_set, igraph_cattribute_VAN, igraph_cattribute_VAN_set,
+    igraph_cattribute_VAS, igraph_cattribute_VAS_set, igraph_cattribute_has_attr,
+    igraph_cattribute_list, igraph_cattribute_remove_e, igraph_cattribute_remove_g,
+    igraph_cattribute_remove_v, igraph_cattribute_table, igraph_error_type_t_IGRAPH_SUCCESS,
+    igraph_strvector_destroy, igraph_strvector_init, igraph_strvector_t,
+};
+
+use crate::{
+    Graph, IgraphError, Result,
+    attributes::{AttributeKind, AttributeValue, Scope, strings},
+    error::{call, check},
+    graph::Directedness,
+};
+
+/// The kind of the attribute `name`, if the graph has one.
+fn kind<D: Directedness>(graph: &Graph<D>, scope: Scope, name: &CStr) -> Option<AttributeKind> {
+    let get_type = unsafe { igraph_cattribute_table.get_type }?;
+    let mut kind = igraph_attribute_type_t_IGRAPH_ATTRIBUTE_UNSPECIFIED;
+    unsafe {
+        if !igraph_cattribute_has_attr(graph.as_ptr(), scope.raw(), name.as_ptr())
+            || get_type(graph.as_ptr(), &mut kind, scope.raw(), name.as_ptr())
+                != igraph_error_type_t_IGRAPH_SUCCESS
+        {
+            return None;
+        }
+    }
+    AttributeKind::from_raw(kind)
+}
+
+fn string(value: *const c_char) -> AttributeValue {
+    unsafe { CStr::from_ptr(value) }
+        .to_string_lossy()
+        .into_owned()
+        .into()
+}
+
+/// The value of attribute `name` for element `id`, which is ignored for graph attributes.
+pub(crate) fn get<D: Directedness>(
+    graph: &Graph<D>,
+    scope: Scope,
+    name: &CStr,
+    id: i64,
+) -> Option<AttributeValue> {
+    let kind = kind(graph, scope, name)?;
+    let (graph, name) = (graph.as_ptr(), name.as_ptr());
+    let value = unsafe {
+        match (scope, kind) {
+            (Scope::Graph, AttributeKind::Numeric) => igraph_cattribute_GAN(graph, name).into(),
+            (Scope::Graph, AttributeKind::Boolean) => igraph_cattribute_GAB(graph, name).into(),
+            (Scope::Graph, AttributeKind::String) => string(igraph_cattribute_GAS(graph, name)),
+            (Scope::Vertices, AttributeKind::Numeric) => {
+                igraph_cattribute_VAN(graph, name, id).into()
+            }
+            (Scope::Vertices, AttributeKind::Boolean) => {
+                igraph_cattribute_VAB(graph, name, id).into()
+            }
+            (Scope::Vertices, AttributeKind::String) => {
+                string(igraph_cattribute_VAS(graph, name, id))
+            }
+            (Scope::Edges, AttributeKind::Numeric) => igraph_cattribute_EAN(graph, name, id).into(),
+            (Scope::Edges, AttributeKind::Boolean) => igraph_cattribute_EAB(graph, name, id).into(),
+            (Scope::Edges, AttributeKind::String) => string(igraph_cattribute_EAS(graph, name, id)),
+            #[cfg(feature = "json")]
+            (_, AttributeKind::Json) => return None,
+        }
+    };
+    Some(value)
+}
+
+/// Sets attribute `name` of element `id`, which is ignored for graph attributes.
+pub(crate) fn set<D: Directedness>(
+    graph: &mut Graph<D>,
+    scope: Scope,
+    name: &CStr,
+    id: i64,
+    value: AttributeValue,
+) -> Result<()> {
+    let (graph, name) = (graph.as_mut_ptr(), name.as_ptr());
+    let value = match value {
+        AttributeValue::String(value) => {
+            let value = CString::new(value).map_err(|_| {
+                IgraphError::invalid_value("string attributes cannot contain NUL bytes")
+            })?;
+            unsafe {
+                return check(match scope {
+                    Scope::Graph => igraph_cattribute_GAS_set(graph, name, value.as_ptr()),
+                    Scope::Vertices => igraph_cattribute_VAS_set(graph, name, id, value.as_ptr()),
+                    Scope::Edges => igraph_cattribute_EAS_set(graph, name, id, value.as_ptr()),
+                });
+            }
+        }
+        #[cfg(feature = "json")]
+        AttributeValue::Json(_) => {
+            return Err(IgraphError::invalid_value(
+                "the C attribute handler cannot store JSON values",
+            ));
+        }
+        value => value,
+    };
+    call(|| unsafe {
+        match (scope, value) {
+            (Scope::Graph, AttributeValue::Numeric(v)) => igraph_cattribute_GAN_set(graph, name, v),
+            (Scope::Graph, AttributeValue::Boolean(v)) => igraph_cattribute_GAB_set(graph, name, v),
+            (Scope::Vertices, AttributeValue::Numeric(v)) => {
+                igraph_cattribute_VAN_set(graph, name, id, v)
+            }
+            (Scope::Vertices, AttributeValue::Boolean(v)) => {
+                igraph_cattribute_VAB_set(graph, name, id, v)
+            }
+            (Scope::Edges, AttributeValue::Numeric(v)) => {
+                igraph_cattribute_EAN_set(graph, name, id, v)
+            }
+            (Scope::Edges, AttributeValue::Boolean(v)) => {
+                igraph_cattribute_EAB_set(graph, name, id, v)
+            }
+            _ => unreachable!("string and JSON values are handled above"),
+        }
+    })
+}
+
+/// Removes attribute `name`, returning whether it existed.
+pub(crate) fn remove<D: Directedness>(graph: &mut Graph<D>, scope: Scope, name: &CStr) -> bool {
+    if kind(graph, scope, name).is_none() {
+        return false;
+    }
+    unsafe {
+        match scope {
+            Scope::Graph => igraph_cattribute_remove_g(graph.as_mut_ptr(), name.as_ptr()),
+            Scope::Vertices => igraph_cattribute_remove_v(graph.as_mut_ptr(), name.as_ptr()),
+            Scope::Edges => igraph_cattribute_remove_e(graph.as_mut_ptr(), name.as_ptr()),
+        }
+    }
+    true
+}
+
+/// The names of the attributes in `scope`, in creation order.
+pub(crate) fn names<D: Directedness>(graph: &Graph<D>, scope: Scope) -> Result<Vec<String>> {
+    let mut list = unsafe { mem::zeroed::<igraph_strvector_t>() };
+    call(|| unsafe { igraph_strvector_init(&mut list, 0) })?;
+    let (no_names, no_types) = (std::ptr::null_mut(), std::ptr::null_mut());
+    let listed = call(|| unsafe {
+        match scope {
+            Scope::Graph => igraph_cattribute_list(
+                graph.as_ptr(),
+                &mut list,
+                no_types,
+                no_names,
+                no_types,
+                no_names,
+                no_types,
+            ),
+            Scope::Vertices => igraph_cattribute_list(
+                graph.as_ptr(),
+                no_names,
+                no_types,
+                &mut list,
+                no_types,
+                no_names,
+                no_types,
+            ),
+            Scope::Edges => igraph_cattribute_list(
+                graph.as_ptr(),
+                no_names,
+                no_types,
+                no_names,
+                no_types,
+                &mut list,
+                no_types,
+            ),
+        }
+    });
+    let names = unsafe { strings(&list) };
+    unsafe { igraph_strvector_destroy(&mut list) };
+    listed.map(|()| names)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, IgraphError, attributes::enable_c_attributes};
+
+    #[test]
+    fn test_c_attributes_graphml_round_trip() {
+        Graph::setup().unwrap();
+        enable_c_attributes();
+        let mut graph = Graph::new(3, true).unwrap();
+        graph.add_edges_from_slice(&[(0, 1), (1, 2)]).unwrap();
+        for (v, name) in ["a", "b", "c"].into_iter().enumerate() {
+            graph.set_vertex_attr("name", v as u32, name).unwrap();
+        }
+        graph.set_edge_attr("weight", 0, 0.5).unwrap();
+        graph.set_edge_attr("weight", 1, 2.0).unwrap();
+        graph.set_graph_attr("directed_path", true).unwrap();
+        assert!(graph.set_edge_attr("weight", 0, "heavy").is_err());
+        assert_eq!(graph.vertex_attr::<String>("name", 2).unwrap(), "c");
+
+        let path = std::env::temp_dir().join(format!("igraph-rs-{}.graphml", std::process::id()));
+        let path = path.to_str().unwrap();
+        graph.write_graph_graphml(path).unwrap();
+        let read = Graph::read_graph_graphml(path, 0);
+        std::fs::remove_file(path).unwrap();
+
+        graph.remove_edge_attr("weight").unwrap();
+        assert!(graph.edge_attr_names().unwrap().is_empty());
+        assert!(graph.remove_edge_attr("weight").is_err());
+
+        // Reading GraphML needs libxml2, which igraph may have been built without.
+        let read = match read {
+            Err(IgraphError::Unimplemented(_)) => return,
+            read => read.unwrap(),
+        };
+
+        assert_eq!(read.vertex_attr::<String>("name", 2).unwrap(), "c");
+        assert_eq!(read.edge_attr::<f64>("weight", 1).unwrap(), 2.0);
+        assert!(read.graph_attr::<bool>("directed_path").unwrap());
+    }
+}
@@ -0,0 +1,129 @@
+//! Vertex centralities.
+
+use igraph_sys::{
+    igraph_betweenness, igraph_closeness, igraph_pagerank,
+    igraph_pagerank_algo_t_IGRAPH_PAGERANK_ALGO_PRPACK,
+};
+
+use crate::{
+    Graph, NeighborMode, Result, VertexSelector, error::call, graph::Directedness, vector::Vector,
+};
+
+impl<D: Directedness> Graph<D> {
+    /// The betweenness of the selected vertices, following edge directions in directed
+    /// graphs, binding
+    /// [igraph_betweenness](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_betweenness).
+    ///
+    /// With `weights`, shortest paths minimize the sum of the weights of their edges.
+    pub fn betweenness(
+        &self,
+        vertices: &VertexSelector<'_>,
+        weights: Option<&[f64]>,
+        normalized: bool,
+    ) -> Result<Vec<f64>> {
+        let weights = self.check_weights(weights)?;
+        let vertices = vertices.to_checked_raw(self)?;
+        let mut betweenness = Vector::<f64>::new()?;
+        call(|| unsafe {
+            igraph_betweenness(
+                self.as_ptr(),
+                weights.as_ptr(),
+                betweenness.as_mut_ptr(),
+                vertices.as_raw(),
+                self.is_directed(),
+                normalized,
+            )
+        })?;
+        Ok(betweenness.into())
+    }
+
+    /// The closeness of the selected vertices, computed over the vertices each one reaches,
+    /// binding
+    /// [igraph_closeness](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_closeness).
+    pub fn closeness(
+        &self,
+        vertices: &VertexSelector<'_>,
+        mode: NeighborMode,
+        weights: Option<&[f64]>,
+        normalized: bool,
+    ) -> Result<Vec<f64>> {
+        let weights = self.check_weights(weights)?;
+        let vertices = vertices.to_checked_raw(self)?;
+        let mut closeness = Vector::<f64>::new()?;
+        call(|| unsafe {
+            igraph_closeness(
+                self.as_ptr(),
+                closeness.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                vertices.as_raw(),
+                mode.raw(),
+                weights.as_ptr(),
+                normalized,
+            )
+        })?;
+        Ok(closeness.into())
+    }
+
+    /// The PageRank of every vertex with damping factor `damping`, following edge
+    /// directions in directed graphs, binding
+    /// [igraph_pagerank](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_pagerank)
+    /// with the PRPACK solver.
+    pub fn pagerank(&self, damping: f64, weights: Option<&[f64]>) -> Result<Vec<f64>> {
+        let weights = self.check_weights(weights)?;
+        let vertices = VertexSelector::All.to_raw()?;
+        let mut pagerank = Vector::<f64>::new()?;
+        call(|| unsafe {
+            igraph_pagerank(
+                self.as_ptr(),
+                weights.as_ptr(),
+                pagerank.as_mut_ptr(),
+                std::ptr::null_mut(),
+                damping,
+                self.is_directed(),
+                vertices.as_raw(),
+                igraph_pagerank_algo_t_IGRAPH_PAGERANK_ALGO_PRPACK,
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(pagerank.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_centralities() {
+        // A square with both diagonals, the 1-3 one being dear.
+        let mut graph = Graph::new(4, false).unwrap();
+        graph
+            .add_edges_from_slice(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3)])
+            .unwrap();
+        let weights = [1.0, 1.0, 1.0, 1.0, 1.0, 10.0];
+
+        let unweighted = graph
+            .betweenness(&VertexSelector::All, None, false)
+            .unwrap();
+        assert_eq!(unweighted, [0.0; 4]);
+        let weighted = graph
+            .betweenness(&VertexSelector::All, Some(&weights), false)
+            .unwrap();
+        assert_eq!(weighted, [0.5, 0.0, 0.5, 0.0]);
+
+        let closeness = graph
+            .closeness(
+                &VertexSelector::All,
+                NeighborMode::All,
+                Some(&weights),
+                false,
+            )
+            .unwrap();
+        assert_eq!(closeness, [1.0 / 3.0, 0.25, 1.0 / 3.0, 0.25]);
+
+        let pagerank = graph.pagerank(0.85, Some(&weights)).unwrap();
+        assert!((pagerank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(pagerank[1] > pagerank[0]);
+    }
+}
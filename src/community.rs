@@ -0,0 +1,116 @@
+//! Community detection.
+
+use igraph_sys::{
+    igraph_community_leiden_simple, igraph_community_multilevel, igraph_leiden_objective_t,
+    igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_CPM,
+    igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_ER,
+    igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_MODULARITY,
+};
+
+use crate::{Graph, Result, error::call, graph::Directedness, vector::Vector};
+
+/// The quality function optimized by [Graph::community_leiden], mirroring
+/// `igraph_leiden_objective_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeidenObjective {
+    /// Modularity, with vertices weighted by their strength.
+    Modularity,
+    /// The constant Potts model, with unit vertex weights.
+    Cpm,
+    /// The Erdős–Rényi model, i.e. the constant Potts model scaled by the graph density.
+    ErdosRenyi,
+}
+
+impl LeidenObjective {
+    pub(crate) fn raw(self) -> igraph_leiden_objective_t {
+        match self {
+            Self::Modularity => igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_MODULARITY,
+            Self::Cpm => igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_CPM,
+            Self::ErdosRenyi => igraph_leiden_objective_t_IGRAPH_LEIDEN_OBJECTIVE_ER,
+        }
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    pub fn community_multilevel(
+        &self,
+        weights: Option<&[f64]>,
+        resolution: f64,
+    ) -> Result<Vec<i64>> {
+        let weights = self.check_weights(weights)?;
+        let mut membership = Vector::<i64>::zeros(self.num_vertices())?;
+        call(|| unsafe {
+            igraph_community_multilevel(
+                self.as_ptr(),
+                weights.as_ptr(),
+                resolution,
+                membership.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(membership.into())
+    }
+
+    /// The community of each vertex and the quality of the partition found by the Leiden
+    /// algorithm, binding
+    /// [igraph_community_leiden_simple](https://igraph.org/c/html/latest/igraph-Community.html#igraph_community_leiden_simple).
+    ///
+    /// `beta` controls the randomness of the refinement step, which draws from the default
+    /// generator; `iterations` bounds the number of passes, `None` running until the
+    /// partition no longer changes.
+    pub fn community_leiden(
+        &self,
+        weights: Option<&[f64]>,
+        objective: LeidenObjective,
+        resolution: f64,
+        beta: f64,
+        iterations: Option<u32>,
+    ) -> Result<(Vec<i64>, f64)> {
+        let weights = self.check_weights(weights)?;
+        let mut membership = Vector::<i64>::zeros(self.num_vertices())?;
+        let mut quality = 0.0;
+        call(|| unsafe {
+            igraph_community_leiden_simple(
+                self.as_ptr(),
+                weights.as_ptr(),
+                objective.raw(),
+                resolution,
+                beta,
+                false,
+                iterations.map_or(-1, i64::from),
+                membership.as_mut_ptr(),
+                std::ptr::null_mut(),
+                &mut quality,
+            )
+        })?;
+        Ok((membership.into(), quality))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weights_split_communities() {
+        // Two triangles of light edges joined by a single heavy edge.
+        let mut graph = Graph::new(6, false).unwrap();
+        graph
+            .add_edges_from_slice(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)])
+            .unwrap();
+        let weights = [0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 10.0];
+
+        let unweighted = graph.community_multilevel(None, 1.0).unwrap();
+        assert_ne!(unweighted[2], unweighted[3]);
+        let weighted = graph.community_multilevel(Some(&weights), 1.0).unwrap();
+        assert_eq!(weighted[2], weighted[3]);
+
+        let (membership, quality) = graph
+            .community_leiden(None, LeidenObjective::Modularity, 1.0, 0.01, None)
+            .unwrap();
+        assert_eq!(membership[0], membership[2]);
+        assert_ne!(membership[2], membership[3]);
+        assert!(quality > 0.0);
+    }
+}
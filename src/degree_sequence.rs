@@ -0,0 +1,634 @@
+//! Random graphs with prescribed degrees, deterministic realizations of degree sequences,
+//! and the tests telling which sequences can be realized.
+//!
+//! Degree sequences are `&[i64]`, one degree per vertex; directed graphs take their
+//! out-degrees and `Some` in-degrees. The random models draw from the default generator
+//! of the calling thread, see [rng::with_default_rng](crate::rng::with_default_rng).
+
+use igraph_sys::{
+    igraph_chung_lu_game, igraph_chung_lu_t, igraph_chung_lu_t_IGRAPH_CHUNG_LU_MAXENT,
+    igraph_chung_lu_t_IGRAPH_CHUNG_LU_NR, igraph_chung_lu_t_IGRAPH_CHUNG_LU_ORIGINAL,
+    igraph_degree_sequence_game, igraph_degseq_t, igraph_degseq_t_IGRAPH_DEGSEQ_CONFIGURATION,
+    igraph_degseq_t_IGRAPH_DEGSEQ_CONFIGURATION_SIMPLE,
+    igraph_degseq_t_IGRAPH_DEGSEQ_EDGE_SWITCHING_SIMPLE,
+    igraph_degseq_t_IGRAPH_DEGSEQ_FAST_HEUR_SIMPLE, igraph_degseq_t_IGRAPH_DEGSEQ_VL,
+    igraph_is_bigraphical, igraph_is_graphical, igraph_k_regular_game,
+    igraph_realize_bipartite_degree_sequence, igraph_realize_degree_sequence,
+    igraph_realize_degseq_t, igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_INDEX,
+    igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_LARGEST,
+    igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_SMALLEST, igraph_static_fitness_game,
+    igraph_static_power_law_game,
+};
+
+use crate::{Graph, Result, error::call, generators::AllowedEdgeTypes, thread, vector::VectorView};
+
+/// How [Graph::degree_sequence_game] samples graphs, mirroring `igraph_degseq_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DegreeSequenceMethod {
+    /// The configuration model: stubs are paired uniformly at random, which may create
+    /// self-loops and multi-edges.
+    Configuration,
+    /// The configuration model, restarted until it yields a simple graph; uniform but
+    /// slow for sequences with large degrees.
+    ConfigurationSimple,
+    /// Pairs stubs at random while avoiding self-loops and multi-edges, restarting on
+    /// dead ends; fast but not uniform.
+    FastHeurSimple,
+    /// Realizes the sequence, then randomizes it with degree-preserving edge switches, a
+    /// Markov chain Monte Carlo method sampling simple graphs close to uniformly.
+    EdgeSwitchingSimple,
+    /// The method of Viger and Latapy, sampling connected simple undirected graphs close
+    /// to uniformly.
+    VigerLatapy,
+}
+
+impl DegreeSequenceMethod {
+    pub(crate) fn raw(self) -> igraph_degseq_t {
+        match self {
+            Self::Configuration => igraph_degseq_t_IGRAPH_DEGSEQ_CONFIGURATION,
+            Self::ConfigurationSimple => igraph_degseq_t_IGRAPH_DEGSEQ_CONFIGURATION_SIMPLE,
+            Self::FastHeurSimple => igraph_degseq_t_IGRAPH_DEGSEQ_FAST_HEUR_SIMPLE,
+            Self::EdgeSwitchingSimple => igraph_degseq_t_IGRAPH_DEGSEQ_EDGE_SWITCHING_SIMPLE,
+            Self::VigerLatapy => igraph_degseq_t_IGRAPH_DEGSEQ_VL,
+        }
+    }
+}
+
+/// The variant of the Chung–Lu model used by [Graph::chung_lu_game], mirroring
+/// `igraph_chung_lu_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChungLuVariant {
+    /// Connects `i` and `j` with probability `min(1, w_i w_j / S)`, where `S` is the sum
+    /// of the weights; the expected degrees are exact unless the minimum kicks in.
+    Original,
+    /// Connects `i` and `j` with probability `q / (1 + q)`, `q = w_i w_j / S`, the
+    /// maximum entropy model with these expected degrees in the limit of large graphs.
+    MaxEnt,
+    /// Connects `i` and `j` with probability `1 - exp(-q)`, `q = w_i w_j / S`, as in the
+    /// model of Norros and Reittu.
+    Nr,
+}
+
+impl ChungLuVariant {
+    pub(crate) fn raw(self) -> igraph_chung_lu_t {
+        match self {
+            Self::Original => igraph_chung_lu_t_IGRAPH_CHUNG_LU_ORIGINAL,
+            Self::MaxEnt => igraph_chung_lu_t_IGRAPH_CHUNG_LU_MAXENT,
+            Self::Nr => igraph_chung_lu_t_IGRAPH_CHUNG_LU_NR,
+        }
+    }
+}
+
+/// The order in which [Graph::realize_degree_sequence] connects vertices, mirroring
+/// `igraph_realize_degseq_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RealizeMethod {
+    /// Connects the vertex of smallest remaining degree to the largest ones first, which
+    /// yields a connected graph whenever the sequence has one.
+    Smallest,
+    /// Connects the vertex of largest remaining degree to the largest ones first, the
+    /// Havel–Hakimi construction, which tends to yield dense cores.
+    Largest,
+    /// Connects the vertices in the order of their ids.
+    Index,
+}
+
+impl RealizeMethod {
+    pub(crate) fn raw(self) -> igraph_realize_degseq_t {
+        match self {
+            Self::Smallest => igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_SMALLEST,
+            Self::Largest => igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_LARGEST,
+            Self::Index => igraph_realize_degseq_t_IGRAPH_REALIZE_DEGSEQ_INDEX,
+        }
+    }
+}
+
+/// Whether a degree sequence can be realized, and why not if it cannot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Graphicality {
+    /// Some graph has these degrees.
+    Graphical,
+    /// The sequence cannot be realized, for the given reason.
+    Rejected(String),
+}
+
+impl Graphicality {
+    /// Whether the sequence can be realized, discarding the reason if it cannot.
+    pub fn is_graphical(&self) -> bool {
+        matches!(self, Self::Graphical)
+    }
+}
+
+fn view_ptr<T: crate::vector::Element>(view: &Option<VectorView<'_, T>>) -> *const T::Raw {
+    view.as_ref().map_or(std::ptr::null(), VectorView::as_ptr)
+}
+
+/// Tells whether `out_degrees`, with `in_degrees` for directed graphs, are the degrees of
+/// a graph with the `allowed` kinds of edges, binding
+/// [igraph_is_graphical](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_is_graphical).
+///
+/// Sequences of different lengths are rejected rather than passed to igraph, which
+/// refuses them with an error.
+pub fn is_graphical(
+    out_degrees: &[i64],
+    in_degrees: Option<&[i64]>,
+    allowed: AllowedEdgeTypes,
+) -> Result<Graphicality> {
+    if let Some(in_degrees) = in_degrees
+        && in_degrees.len() != out_degrees.len()
+    {
+        return Ok(Graphicality::Rejected(format!(
+            "there are {} out-degrees but {} in-degrees",
+            out_degrees.len(),
+            in_degrees.len()
+        )));
+    }
+    thread::setup()?;
+    let mut graphical = false;
+    call(|| unsafe {
+        igraph_is_graphical(
+            VectorView::new(out_degrees).as_ptr(),
+            view_ptr(&in_degrees.map(VectorView::new)),
+            allowed.raw(),
+            &mut graphical,
+        )
+    })?;
+    Ok(if graphical {
+        Graphicality::Graphical
+    } else {
+        Graphicality::Rejected(match in_degrees {
+            None => explain_undirected(out_degrees, allowed),
+            Some(in_degrees) => explain_directed(out_degrees, in_degrees, allowed),
+        })
+    })
+}
+
+/// Tells whether `degrees1` and `degrees2` are the degrees of the two sides of a
+/// bipartite graph with the `allowed` kinds of edges, binding
+/// [igraph_is_bigraphical](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_is_bigraphical).
+pub fn is_bigraphical(
+    degrees1: &[i64],
+    degrees2: &[i64],
+    allowed: AllowedEdgeTypes,
+) -> Result<Graphicality> {
+    thread::setup()?;
+    let mut graphical = false;
+    call(|| unsafe {
+        igraph_is_bigraphical(
+            VectorView::new(degrees1).as_ptr(),
+            VectorView::new(degrees2).as_ptr(),
+            allowed.raw(),
+            &mut graphical,
+        )
+    })?;
+    Ok(if graphical {
+        Graphicality::Graphical
+    } else {
+        Graphicality::Rejected(explain_bipartite(degrees1, degrees2, allowed))
+    })
+}
+
+const UNEXPLAINED: &str = "igraph rejects the sequence";
+
+fn negative(degrees: &[i64], what: &str) -> Option<String> {
+    let v = degrees.iter().position(|&d| d < 0)?;
+    Some(format!(
+        "the {what} {} of vertex {v} is negative",
+        degrees[v]
+    ))
+}
+
+/// The degrees sorted in decreasing order.
+fn decreasing(degrees: &[i64]) -> Vec<i64> {
+    let mut sorted = degrees.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted
+}
+
+fn explain_undirected(degrees: &[i64], allowed: AllowedEdgeTypes) -> String {
+    if let Some(reason) = negative(degrees, "degree") {
+        return reason;
+    }
+    let sum: i64 = degrees.iter().sum();
+    if sum % 2 != 0 {
+        return format!("the degrees sum to {sum}, which is odd");
+    }
+    if allowed.multi() {
+        // Without self-loops, a vertex must find the ends of its edges on the others.
+        if let Some((v, &d)) = degrees.iter().enumerate().max_by_key(|&(_, &d)| d)
+            && !allowed.loops()
+            && d > sum - d
+        {
+            return format!(
+                "vertex {v} has degree {d}, more than the {} of all other vertices together",
+                sum - d
+            );
+        }
+        return UNEXPLAINED.to_string();
+    }
+    // A self-loop adds two to the degree but uses no other vertex.
+    let loops = if allowed.loops() { 1 } else { -1 };
+    let n = degrees.len() as i64;
+    if let Some((v, &d)) = degrees.iter().enumerate().max_by_key(|&(_, &d)| d)
+        && !allowed.loops()
+        && d > n - 1
+    {
+        return format!(
+            "vertex {v} has degree {d} but there are only {} other vertices",
+            n - 1
+        );
+    }
+    let sorted = decreasing(degrees);
+    let mut head = 0;
+    for k in 1..=sorted.len() {
+        head += sorted[k - 1];
+        let k = k as i64;
+        let bound = k * (k + loops) + sorted[k as usize..].iter().map(|&d| d.min(k)).sum::<i64>();
+        if head > bound {
+            return format!(
+                "the {k} largest degrees sum to {head}, more than the bound {bound} of the \
+                 Erdős–Gallai inequality"
+            );
+        }
+    }
+    UNEXPLAINED.to_string()
+}
+
+fn explain_directed(out_degrees: &[i64], in_degrees: &[i64], allowed: AllowedEdgeTypes) -> String {
+    if let Some(reason) =
+        negative(out_degrees, "out-degree").or_else(|| negative(in_degrees, "in-degree"))
+    {
+        return reason;
+    }
+    let (out_sum, in_sum): (i64, i64) = (out_degrees.iter().sum(), in_degrees.iter().sum());
+    if out_sum != in_sum {
+        return format!("the out-degrees sum to {out_sum} but the in-degrees to {in_sum}");
+    }
+    if allowed.multi() {
+        if !allowed.loops()
+            && let Some(v) =
+                (0..out_degrees.len()).find(|&v| out_degrees[v] + in_degrees[v] > out_sum)
+        {
+            return format!(
+                "vertex {v} has {} out- and {} in-edges, more than the {out_sum} edges of the \
+                 graph without self-loops",
+                out_degrees[v], in_degrees[v]
+            );
+        }
+        return UNEXPLAINED.to_string();
+    }
+    let n = out_degrees.len() as i64;
+    if !allowed.loops()
+        && let Some(v) =
+            (0..out_degrees.len()).find(|&v| out_degrees[v] > n - 1 || in_degrees[v] > n - 1)
+    {
+        return format!(
+            "vertex {v} has out-degree {} and in-degree {} but there are only {} other vertices",
+            out_degrees[v],
+            in_degrees[v],
+            n - 1
+        );
+    }
+    // The Fulkerson–Chen–Anstee inequalities, over vertices in lexicographically
+    // decreasing order of out- and in-degree; with self-loops allowed, those of Gale–Ryser.
+    let mut pairs: Vec<(i64, i64)> = out_degrees
+        .iter()
+        .copied()
+        .zip(in_degrees.iter().copied())
+        .collect();
+    pairs.sort_unstable_by(|a, b| b.cmp(a));
+    let inequality = if allowed.loops() {
+        "Gale–Ryser"
+    } else {
+        "Fulkerson–Chen–Anstee"
+    };
+    let mut head = 0;
+    for k in 1..=pairs.len() {
+        head += pairs[k - 1].0;
+        let k = k as i64;
+        let bound: i64 = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, d))| {
+                if allowed.loops() || i as i64 >= k {
+                    d.min(k)
+                } else {
+                    d.min(k - 1)
+                }
+            })
+            .sum();
+        if head > bound {
+            return format!(
+                "the {k} largest out-degrees sum to {head}, more than the bound {bound} of the \
+                 {inequality} inequality"
+            );
+        }
+    }
+    UNEXPLAINED.to_string()
+}
+
+fn explain_bipartite(degrees1: &[i64], degrees2: &[i64], allowed: AllowedEdgeTypes) -> String {
+    if let Some(reason) = negative(degrees1, "degree").or_else(|| negative(degrees2, "degree")) {
+        return reason;
+    }
+    let (sum1, sum2): (i64, i64) = (degrees1.iter().sum(), degrees2.iter().sum());
+    if sum1 != sum2 {
+        return format!(
+            "the degrees of the first side sum to {sum1} but those of the second to {sum2}"
+        );
+    }
+    if allowed.multi() {
+        return UNEXPLAINED.to_string();
+    }
+    // The Gale–Ryser inequalities.
+    let sorted = decreasing(degrees1);
+    let mut head = 0;
+    for k in 1..=sorted.len() {
+        head += sorted[k - 1];
+        let bound: i64 = degrees2.iter().map(|&d| d.min(k as i64)).sum();
+        if head > bound {
+            return format!(
+                "the {k} largest degrees of the first side sum to {head}, more than the bound \
+                 {bound} of the Gale–Ryser inequality"
+            );
+        }
+    }
+    UNEXPLAINED.to_string()
+}
+
+impl Graph {
+    /// A random graph with the given degrees, binding
+    /// [igraph_degree_sequence_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_degree_sequence_game);
+    /// directed if `in_degrees` is given.
+    pub fn degree_sequence_game(
+        out_degrees: &[i64],
+        in_degrees: Option<&[i64]>,
+        method: DegreeSequenceMethod,
+    ) -> Result<Self> {
+        let in_degrees = in_degrees.map(VectorView::new);
+        Self::init_with(|graph| unsafe {
+            igraph_degree_sequence_game(
+                graph,
+                VectorView::new(out_degrees).as_ptr(),
+                view_ptr::<i64>(&in_degrees),
+                method.raw(),
+            )
+        })
+    }
+
+    /// A random `degree`-regular graph on `num_vertices` vertices, binding
+    /// [igraph_k_regular_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_k_regular_game);
+    /// directed graphs have `degree` out- and in-edges at every vertex.
+    pub fn k_regular_game(
+        num_vertices: usize,
+        degree: usize,
+        directed: bool,
+        multiple: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_k_regular_game(
+                graph,
+                num_vertices as i64,
+                degree as i64,
+                directed,
+                multiple,
+            )
+        })
+    }
+
+    /// A random graph whose vertices have the given expected degrees, binding
+    /// [igraph_chung_lu_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_chung_lu_game);
+    /// directed if `expected_in_degrees` is given.
+    pub fn chung_lu_game(
+        expected_out_degrees: &[f64],
+        expected_in_degrees: Option<&[f64]>,
+        loops: bool,
+        variant: ChungLuVariant,
+    ) -> Result<Self> {
+        let in_degrees = expected_in_degrees.map(VectorView::new);
+        Self::init_with(|graph| unsafe {
+            igraph_chung_lu_game(
+                graph,
+                VectorView::new(expected_out_degrees).as_ptr(),
+                view_ptr::<f64>(&in_degrees),
+                loops,
+                variant.raw(),
+            )
+        })
+    }
+
+    /// A random graph with `num_edges` edges whose ends are drawn with probability
+    /// proportional to the fitness of the vertices, binding
+    /// [igraph_static_fitness_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_static_fitness_game);
+    /// directed if `fitness_in` is given.
+    pub fn static_fitness_game(
+        num_edges: usize,
+        fitness_out: &[f64],
+        fitness_in: Option<&[f64]>,
+        allowed: AllowedEdgeTypes,
+    ) -> Result<Self> {
+        let fitness_in = fitness_in.map(VectorView::new);
+        Self::init_with(|graph| unsafe {
+            igraph_static_fitness_game(
+                graph,
+                num_edges as i64,
+                VectorView::new(fitness_out).as_ptr(),
+                view_ptr::<f64>(&fitness_in),
+                allowed.raw(),
+            )
+        })
+    }
+
+    /// A random graph whose degrees follow a power law with the given exponent, binding
+    /// [igraph_static_power_law_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_static_power_law_game);
+    /// directed if `exponent_in` is given.
+    ///
+    /// `finite_size_correction` lowers the fitness of the hubs, which would otherwise get
+    /// fewer edges than the power law predicts.
+    pub fn static_power_law_game(
+        num_vertices: usize,
+        num_edges: usize,
+        exponent_out: f64,
+        exponent_in: Option<f64>,
+        allowed: AllowedEdgeTypes,
+        finite_size_correction: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_static_power_law_game(
+                graph,
+                num_vertices as i64,
+                num_edges as i64,
+                exponent_out,
+                exponent_in.unwrap_or(-1.0),
+                allowed.raw(),
+                finite_size_correction,
+            )
+        })
+    }
+
+    /// A graph with exactly the given degrees, built deterministically, binding
+    /// [igraph_realize_degree_sequence](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_realize_degree_sequence);
+    /// directed if `in_degrees` is given. See [is_graphical] for why a sequence may be rejected.
+    pub fn realize_degree_sequence(
+        out_degrees: &[i64],
+        in_degrees: Option<&[i64]>,
+        allowed: AllowedEdgeTypes,
+        method: RealizeMethod,
+    ) -> Result<Self> {
+        let in_degrees = in_degrees.map(VectorView::new);
+        Self::init_with(|graph| unsafe {
+            igraph_realize_degree_sequence(
+                graph,
+                VectorView::new(out_degrees).as_ptr(),
+                view_ptr::<i64>(&in_degrees),
+                allowed.raw(),
+                method.raw(),
+            )
+        })
+    }
+
+    /// A bipartite graph whose two sides have exactly the given degrees, built
+    /// deterministically, binding
+    /// [igraph_realize_bipartite_degree_sequence](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_realize_bipartite_degree_sequence);
+    /// the vertices of the first side come first. See [is_bigraphical] for why a sequence
+    /// may be rejected.
+    pub fn realize_bipartite_degree_sequence(
+        degrees1: &[i64],
+        degrees2: &[i64],
+        allowed: AllowedEdgeTypes,
+        method: RealizeMethod,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_realize_bipartite_degree_sequence(
+                graph,
+                VectorView::new(degrees1).as_ptr(),
+                VectorView::new(degrees2).as_ptr(),
+                allowed.raw(),
+                method.raw(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Loops, NeighborMode, VertexSelector};
+
+    fn degrees(graph: &Graph, mode: NeighborMode) -> Vec<i64> {
+        graph
+            .strength(&VertexSelector::All, mode, Loops::Twice, None)
+            .unwrap()
+            .into_iter()
+            .map(|d| d as i64)
+            .collect()
+    }
+
+    #[test]
+    fn test_degree_sequence_games() {
+        let sequence = [3, 3, 2, 2, 2, 1, 1];
+        for method in [
+            DegreeSequenceMethod::Configuration,
+            DegreeSequenceMethod::ConfigurationSimple,
+            DegreeSequenceMethod::FastHeurSimple,
+            DegreeSequenceMethod::EdgeSwitchingSimple,
+        ] {
+            let graph = Graph::degree_sequence_game(&sequence, None, method).unwrap();
+            assert_eq!(degrees(&graph, NeighborMode::All), sequence);
+        }
+        let connected = [2, 2, 2, 2, 2, 2];
+        let graph =
+            Graph::degree_sequence_game(&connected, None, DegreeSequenceMethod::VigerLatapy)
+                .unwrap();
+        assert_eq!(degrees(&graph, NeighborMode::All), connected);
+
+        let regular = Graph::k_regular_game(10, 3, false, false).unwrap();
+        assert_eq!(degrees(&regular, NeighborMode::All), [3; 10]);
+
+        let chung_lu =
+            Graph::chung_lu_game(&[2.0; 20], None, false, ChungLuVariant::Original).unwrap();
+        assert_eq!(chung_lu.num_vertices(), 20);
+        let fitness =
+            Graph::static_fitness_game(30, &[1.0; 20], Some(&[2.0; 20]), AllowedEdgeTypes::Simple)
+                .unwrap();
+        assert_eq!((fitness.num_edges(), fitness.is_directed()), (30, true));
+        let power_law =
+            Graph::static_power_law_game(50, 60, 2.5, None, AllowedEdgeTypes::Simple, true)
+                .unwrap();
+        assert_eq!(
+            (power_law.num_edges(), power_law.is_directed()),
+            (60, false)
+        );
+    }
+
+    #[test]
+    fn test_realizations() {
+        let graph = Graph::realize_degree_sequence(
+            &[2, 1, 1],
+            Some(&[1, 1, 2]),
+            AllowedEdgeTypes::Simple,
+            RealizeMethod::Smallest,
+        )
+        .unwrap();
+        assert_eq!(degrees(&graph, NeighborMode::Out), [2, 1, 1]);
+        assert_eq!(degrees(&graph, NeighborMode::In), [1, 1, 2]);
+
+        let bipartite = Graph::realize_bipartite_degree_sequence(
+            &[2, 2],
+            &[1, 1, 2],
+            AllowedEdgeTypes::Simple,
+            RealizeMethod::Largest,
+        )
+        .unwrap();
+        assert_eq!(degrees(&bipartite, NeighborMode::All), [2, 2, 1, 1, 2]);
+
+        assert!(
+            Graph::realize_degree_sequence(
+                &[3, 1],
+                None,
+                AllowedEdgeTypes::Simple,
+                RealizeMethod::Index
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rejections_are_explained() {
+        let simple = AllowedEdgeTypes::Simple;
+        assert!(
+            is_graphical(&[2, 2, 2], None, simple)
+                .unwrap()
+                .is_graphical()
+        );
+
+        let rejected = |result: Result<Graphicality>| match result.unwrap() {
+            Graphicality::Rejected(reason) => reason,
+            Graphicality::Graphical => panic!("the sequence was accepted"),
+        };
+        assert!(rejected(is_graphical(&[2, -1, 1], None, simple)).contains("negative"));
+        assert!(rejected(is_graphical(&[2, 2, 1], None, simple)).contains("odd"));
+        assert!(rejected(is_graphical(&[3, 1], None, simple)).contains("only 1 other"));
+        assert!(rejected(is_graphical(&[3, 3, 3, 1], None, simple)).contains("Erdős–Gallai"));
+        assert!(
+            rejected(is_graphical(&[4, 2], None, AllowedEdgeTypes::Multi)).contains("vertex 0")
+        );
+        assert!(
+            is_graphical(&[4, 2], None, AllowedEdgeTypes::LoopsAndMulti)
+                .unwrap()
+                .is_graphical()
+        );
+
+        assert!(rejected(is_graphical(&[1, 1], Some(&[1]), simple)).contains("in-degrees"));
+        assert!(rejected(is_graphical(&[2, 0], Some(&[0, 1]), simple)).contains("sum"));
+        assert!(rejected(is_graphical(&[2, 0, 0], Some(&[0, 2, 0]), simple)).contains("Fulkerson"));
+
+        assert!(
+            is_bigraphical(&[2, 2], &[1, 1, 2], simple)
+                .unwrap()
+                .is_graphical()
+        );
+        assert!(rejected(is_bigraphical(&[3], &[1, 2], simple)).contains("Gale"));
+        assert!(rejected(is_bigraphical(&[3], &[1, 1], simple)).contains("sum"));
+    }
+}
@@ -0,0 +1,221 @@
+//! Algorithms that only make sense on directed graphs, available on `Graph<Directed>`.
+
+use igraph_sys::{
+    igraph_dominator_tree, igraph_hub_and_authority_scores, igraph_reciprocity,
+    igraph_reciprocity_t, igraph_reciprocity_t_IGRAPH_RECIPROCITY_DEFAULT,
+    igraph_reciprocity_t_IGRAPH_RECIPROCITY_RATIO, igraph_topological_sorting,
+};
+
+use crate::{Graph, NeighborMode, Result, VertexId, error::call, graph::Directed, vector::Vector};
+
+/// What [Graph::reciprocity] measures, mirroring `igraph_reciprocity_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReciprocityMode {
+    /// The fraction of edges whose reverse edge is also in the graph.
+    Default,
+    /// The fraction of connected vertex pairs that are connected in both directions.
+    Ratio,
+}
+
+impl ReciprocityMode {
+    pub(crate) fn raw(self) -> igraph_reciprocity_t {
+        match self {
+            Self::Default => igraph_reciprocity_t_IGRAPH_RECIPROCITY_DEFAULT,
+            Self::Ratio => igraph_reciprocity_t_IGRAPH_RECIPROCITY_RATIO,
+        }
+    }
+}
+
+/// The result of [Graph::dominator_tree].
+#[derive(Debug)]
+pub struct DominatorTree {
+    /// The immediate dominator of each vertex, `None` for the root and for the vertices
+    /// that cannot be reached from it.
+    pub dominators: Vec<Option<VertexId>>,
+    /// The dominator tree, with an edge from each immediate dominator to the vertices it
+    /// dominates, on the same vertex ids as the graph.
+    pub tree: Graph<Directed>,
+    /// The vertices that cannot be reached from the root.
+    pub left_out: Vec<VertexId>,
+}
+
+/// The result of [Graph::hub_and_authority_scores].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HubsAndAuthorities {
+    /// The hub score of each vertex, scaled so that the largest is one.
+    pub hubs: Vec<f64>,
+    /// The authority score of each vertex, scaled so that the largest is one.
+    pub authorities: Vec<f64>,
+    /// The eigenvalue of the hub and authority matrices.
+    pub eigenvalue: f64,
+}
+
+impl Graph<Directed> {
+    /// A topological ordering of the vertices, binding
+    /// [igraph_topological_sorting](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_topological_sorting).
+    ///
+    /// With [NeighborMode::Out] every vertex comes before its out-neighbors, with
+    /// [NeighborMode::In] before its in-neighbors. Fails if the graph has a cycle.
+    pub fn topological_sorting(&self, mode: NeighborMode) -> Result<Vec<VertexId>> {
+        let mut order = Vector::<i64>::new()?;
+        call(|| unsafe {
+            igraph_topological_sorting(self.as_ptr(), order.as_mut_ptr(), mode.raw())
+        })?;
+        Ok(order.iter().copied().map(VertexId::from_raw).collect())
+    }
+
+    /// The dominator tree of the vertices reachable from `root`, binding
+    /// [igraph_dominator_tree](https://igraph.org/c/html/latest/igraph-Flows.html#igraph_dominator_tree).
+    ///
+    /// `mode` is [NeighborMode::Out] to follow the edges from the root or
+    /// [NeighborMode::In] to follow them in reverse.
+    pub fn dominator_tree(
+        &self,
+        root: impl Into<VertexId>,
+        mode: NeighborMode,
+    ) -> Result<DominatorTree> {
+        let root = self.check_vertex(root.into())?;
+        let mut dominators = Vector::<i64>::new()?;
+        let mut left_out = Vector::<i64>::new()?;
+        let tree = Graph::init_with(|tree| unsafe {
+            igraph_dominator_tree(
+                self.as_ptr(),
+                root.get(),
+                dominators.as_mut_ptr(),
+                tree,
+                left_out.as_mut_ptr(),
+                mode.raw(),
+            )
+        })?;
+        Ok(DominatorTree {
+            dominators: dominators
+                .iter()
+                .enumerate()
+                .map(|(v, &dominator)| {
+                    (dominator >= 0 && v != root.index()).then(|| VertexId::from_raw(dominator))
+                })
+                .collect(),
+            tree,
+            left_out: left_out.iter().copied().map(VertexId::from_raw).collect(),
+        })
+    }
+
+    /// The reciprocity of the graph, binding
+    /// [igraph_reciprocity](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_reciprocity).
+    pub fn reciprocity(&self, ignore_loops: bool, mode: ReciprocityMode) -> Result<f64> {
+        let mut reciprocity = 0.0;
+        call(|| unsafe {
+            igraph_reciprocity(self.as_ptr(), &mut reciprocity, ignore_loops, mode.raw())
+        })?;
+        Ok(reciprocity)
+    }
+
+    /// Kleinberg's hub and authority scores, binding
+    /// [igraph_hub_and_authority_scores](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_hub_and_authority_scores)
+    /// with the default ARPACK options.
+    pub fn hub_and_authority_scores(&self, weights: Option<&[f64]>) -> Result<HubsAndAuthorities> {
+        let weights = self.check_weights(weights)?;
+        let mut hubs = Vector::<f64>::new()?;
+        let mut authorities = Vector::<f64>::new()?;
+        let mut eigenvalue = 0.0;
+        call(|| unsafe {
+            igraph_hub_and_authority_scores(
+                self.as_ptr(),
+                hubs.as_mut_ptr(),
+                authorities.as_mut_ptr(),
+                &mut eigenvalue,
+                weights.as_ptr(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(HubsAndAuthorities {
+            hubs: hubs.into(),
+            authorities: authorities.into(),
+            eigenvalue,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ToDirected, ToUndirected, Undirected};
+
+    fn directed(n: usize, edges: &[(u32, u32)]) -> Graph<Directed> {
+        let mut graph = Graph::<Directed>::empty(n).unwrap();
+        graph.add_edges_from_slice(edges).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_topological_sorting() {
+        let dag = directed(4, &[(2, 0), (0, 1), (3, 1), (2, 3)]);
+        let order: Vec<i64> = dag
+            .topological_sorting(NeighborMode::Out)
+            .unwrap()
+            .into_iter()
+            .map(i64::from)
+            .collect();
+        assert_eq!(order, [2, 0, 3, 1]);
+
+        let cycle = directed(3, &[(0, 1), (1, 2), (2, 0)]);
+        assert!(cycle.topological_sorting(NeighborMode::Out).is_err());
+    }
+
+    #[test]
+    fn test_dominator_tree() {
+        let graph = directed(5, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let tree = graph.dominator_tree(0, NeighborMode::Out).unwrap();
+        let dominators: Vec<Option<i64>> =
+            tree.dominators.iter().map(|d| d.map(i64::from)).collect();
+        assert_eq!(dominators, [None, Some(0), Some(0), Some(0), None]);
+        assert_eq!(tree.left_out, [VertexId::from(4)]);
+        assert_eq!(tree.tree.num_edges(), 3);
+    }
+
+    #[test]
+    fn test_reciprocity_and_hubs() {
+        let graph = directed(3, &[(0, 1), (1, 0), (1, 2)]);
+        let default = graph.reciprocity(true, ReciprocityMode::Default).unwrap();
+        assert!((default - 2.0 / 3.0).abs() < 1e-12);
+        let ratio = graph.reciprocity(true, ReciprocityMode::Ratio).unwrap();
+        assert!((ratio - 0.5).abs() < 1e-12);
+
+        let star = directed(4, &[(0, 3), (1, 3), (2, 3)]);
+        let scores = star.hub_and_authority_scores(None).unwrap();
+        assert_eq!(scores.authorities.len(), 4);
+        assert!((scores.authorities[3] - 1.0).abs() < 1e-9);
+        assert!(scores.hubs[3].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_directedness_conversions() {
+        let mut dynamic = Graph::new(3, false).unwrap();
+        dynamic.add_edges_from_slice(&[(0, 1), (1, 2)]).unwrap();
+        let undirected = Graph::<Undirected>::try_from(dynamic).unwrap();
+        let arbitrary = undirected
+            .clone()
+            .into_directed(ToDirected::Arbitrary)
+            .unwrap();
+        assert_eq!(arbitrary.num_edges(), 2);
+        let mutual = undirected.into_directed(ToDirected::Mutual).unwrap();
+        assert_eq!(mutual.num_edges(), 4);
+        assert_eq!(
+            mutual.reciprocity(true, ReciprocityMode::Default).unwrap(),
+            1.0
+        );
+        assert!(Graph::<Undirected>::try_from(mutual.into_dynamic()).is_err());
+
+        let graph = directed(3, &[(0, 1), (1, 0), (1, 2)]);
+        let collapsed = graph
+            .clone()
+            .into_undirected(ToUndirected::Collapse)
+            .unwrap();
+        assert!(!collapsed.is_directed());
+        assert_eq!(collapsed.num_edges(), 2);
+        let each = graph.clone().into_undirected(ToUndirected::Each).unwrap();
+        assert_eq!(each.num_edges(), 3);
+        let mutual = graph.into_undirected(ToUndirected::Mutual).unwrap();
+        assert_eq!(mutual.num_edges(), 1);
+    }
+}
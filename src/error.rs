@@ -0,0 +1,340 @@
+//! Typed errors for the safe wrappers.
+//!
+//! Every igraph C function returns an `igraph_error_t`; the safe layer turns
+//! any code other than `IGRAPH_SUCCESS` into an [IgraphError] carrying the
+//! [igraph_strerror](https://igraph.org/c/html/latest/igraph-Error.html#igraph_strerror)
+//! description together with the reason, source file and line that igraph
+//! reported through the error handler installed by [install_error_handler].
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, c_char, c_int},
+    fmt,
+};
+
+use igraph_sys::{
+    igraph_error_handler_ignore, igraph_error_t, igraph_error_type_t_IGRAPH_DIVERGED,
+    igraph_error_type_t_IGRAPH_EARPACK, igraph_error_type_t_IGRAPH_EATTRCOMBINE,
+    igraph_error_type_t_IGRAPH_EFILE, igraph_error_type_t_IGRAPH_EINTERNAL,
+    igraph_error_type_t_IGRAPH_EINVAL, igraph_error_type_t_IGRAPH_EINVEID,
+    igraph_error_type_t_IGRAPH_EINVMODE, igraph_error_type_t_IGRAPH_EINVVID,
+    igraph_error_type_t_IGRAPH_ENEGCYCLE, igraph_error_type_t_IGRAPH_ENOMEM,
+    igraph_error_type_t_IGRAPH_ENOSOL, igraph_error_type_t_IGRAPH_EOVERFLOW,
+    igraph_error_type_t_IGRAPH_ERANGE, igraph_error_type_t_IGRAPH_ERWSTUCK,
+    igraph_error_type_t_IGRAPH_EUNDERFLOW, igraph_error_type_t_IGRAPH_EXISTS,
+    igraph_error_type_t_IGRAPH_FAILURE, igraph_error_type_t_IGRAPH_INTERRUPTED,
+    igraph_error_type_t_IGRAPH_PARSEERROR, igraph_error_type_t_IGRAPH_STOP,
+    igraph_error_type_t_IGRAPH_SUCCESS, igraph_error_type_t_IGRAPH_UNIMPLEMENTED,
+    igraph_set_error_handler, igraph_strerror,
+};
+
+use crate::version::Version;
+
+/// Result type returned by every safe wrapper in this crate.
+pub type Result<T> = std::result::Result<T, IgraphError>;
+
+/// What igraph told us about a failed call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The generic description of the error code, as given by `igraph_strerror`.
+    pub description: String,
+    /// The specific reason reported by the function that failed.
+    pub reason: String,
+    /// The C source file in which the error was raised.
+    pub file: String,
+    /// The line of `file` at which the error was raised.
+    pub line: i32,
+}
+
+/// An error raised by igraph, one variant per `IGRAPH_*` error code.
+#[derive(Debug)]
+pub enum IgraphError {
+    /// `IGRAPH_FAILURE`: something went wrong, no more specific code applies.
+    Failure(ErrorContext),
+    /// `IGRAPH_ENOMEM`: there was not enough memory on the heap.
+    OutOfMemory(ErrorContext),
+    /// `IGRAPH_PARSEERROR`: a parse error was found in a file.
+    Parse(ErrorContext),
+    /// `IGRAPH_EINVAL`: a parameter's value is invalid.
+    InvalidValue(ErrorContext),
+    /// `IGRAPH_EXISTS`: an attribute with the given name is already installed.
+    Exists(ErrorContext),
+    /// `IGRAPH_EINVVID`: invalid vertex id, negative or too big.
+    InvalidVertexId(ErrorContext),
+    /// `IGRAPH_EINVEID`: invalid edge id, negative or too big.
+    InvalidEdgeId(ErrorContext),
+    /// `IGRAPH_EINVMODE`: invalid mode parameter.
+    InvalidMode(ErrorContext),
+    /// `IGRAPH_EFILE`: a file operation failed.
+    File(ErrorContext),
+    /// `IGRAPH_UNIMPLEMENTED`: the function is unimplemented or disabled at compile time.
+    Unimplemented(ErrorContext),
+    /// `IGRAPH_INTERRUPTED`: the computation was interrupted.
+    Interrupted(ErrorContext),
+    /// `IGRAPH_DIVERGED`: a numeric algorithm failed to converge.
+    Diverged(ErrorContext),
+    /// `IGRAPH_EARPACK`: an error happened inside ARPACK.
+    Arpack(ErrorContext),
+    /// `IGRAPH_ENEGCYCLE`: a negative cycle was detected while computing shortest paths.
+    NegativeCycle(ErrorContext),
+    /// `IGRAPH_EINTERNAL`: internal error, likely a bug in igraph.
+    Internal(ErrorContext),
+    /// `IGRAPH_EATTRCOMBINE`: unimplemented attribute combination for the attribute type.
+    AttributeCombine(ErrorContext),
+    /// `IGRAPH_EOVERFLOW`: integer or double overflow.
+    Overflow(ErrorContext),
+    /// `IGRAPH_EUNDERFLOW`: integer or double underflow.
+    Underflow(ErrorContext),
+    /// `IGRAPH_ERWSTUCK`: a random walk got stuck.
+    RandomWalkStuck(ErrorContext),
+    /// `IGRAPH_STOP`: a callback requested the computation to stop.
+    Stop(ErrorContext),
+    /// `IGRAPH_ERANGE`: maximum vertex or edge count exceeded.
+    Range(ErrorContext),
+    /// `IGRAPH_ENOSOL`: the input problem has no solution.
+    NoSolution(ErrorContext),
+    /// An error code this crate does not know about.
+    Unknown(igraph_error_t, ErrorContext),
+    /// An error raised on the Rust side before reaching igraph, e.g. a file that cannot be opened.
+    Io(std::io::Error),
+    /// The linked libigraph has a different major or minor version than the headers the bindings were generated from.
+    VersionMismatch { bindings: Version, linked: Version },
+}
+
+impl IgraphError {
+    /// Builds the error corresponding to `code`, which must not be `IGRAPH_SUCCESS`.
+    pub fn from_code(code: igraph_error_t, context: ErrorContext) -> Self {
+        #[allow(non_upper_case_globals)]
+        match code {
+            igraph_error_type_t_IGRAPH_FAILURE => Self::Failure(context),
+            igraph_error_type_t_IGRAPH_ENOMEM => Self::OutOfMemory(context),
+            igraph_error_type_t_IGRAPH_PARSEERROR => Self::Parse(context),
+            igraph_error_type_t_IGRAPH_EINVAL => Self::InvalidValue(context),
+            igraph_error_type_t_IGRAPH_EXISTS => Self::Exists(context),
+            igraph_error_type_t_IGRAPH_EINVVID => Self::InvalidVertexId(context),
+            igraph_error_type_t_IGRAPH_EINVEID => Self::InvalidEdgeId(context),
+            igraph_error_type_t_IGRAPH_EINVMODE => Self::InvalidMode(context),
+            igraph_error_type_t_IGRAPH_EFILE => Self::File(context),
+            igraph_error_type_t_IGRAPH_UNIMPLEMENTED => Self::Unimplemented(context),
+            igraph_error_type_t_IGRAPH_INTERRUPTED => Self::Interrupted(context),
+            igraph_error_type_t_IGRAPH_DIVERGED => Self::Diverged(context),
+            igraph_error_type_t_IGRAPH_EARPACK => Self::Arpack(context),
+            igraph_error_type_t_IGRAPH_ENEGCYCLE => Self::NegativeCycle(context),
+            igraph_error_type_t_IGRAPH_EINTERNAL => Self::Internal(context),
+            igraph_error_type_t_IGRAPH_EATTRCOMBINE => Self::AttributeCombine(context),
+            igraph_error_type_t_IGRAPH_EOVERFLOW => Self::Overflow(context),
+            igraph_error_type_t_IGRAPH_EUNDERFLOW => Self::Underflow(context),
+            igraph_error_type_t_IGRAPH_ERWSTUCK => Self::RandomWalkStuck(context),
+            igraph_error_type_t_IGRAPH_STOP => Self::Stop(context),
+            igraph_error_type_t_IGRAPH_ERANGE => Self::Range(context),
+            igraph_error_type_t_IGRAPH_ENOSOL => Self::NoSolution(context),
+            _ => Self::Unknown(code, context),
+        }
+    }
+
+    /// An error with `code` detected by the safe layer before calling igraph, located at
+    /// the caller of the function rejecting the argument.
+    #[track_caller]
+    fn detected(code: igraph_error_t, reason: impl Into<String>) -> Self {
+        let location = std::panic::Location::caller();
+        Self::from_code(
+            code,
+            ErrorContext {
+                description: lossy(unsafe { igraph_strerror(code) }),
+                reason: reason.into(),
+                file: location.file().to_string(),
+                line: location.line() as i32,
+            },
+        )
+    }
+
+    /// An `IGRAPH_EINVAL` error detected by the safe layer before calling igraph.
+    #[track_caller]
+    pub(crate) fn invalid_value(reason: impl Into<String>) -> Self {
+        Self::detected(igraph_error_type_t_IGRAPH_EINVAL, reason)
+    }
+
+    /// An `IGRAPH_EINVVID` error detected by the safe layer before calling igraph.
+    #[track_caller]
+    pub(crate) fn invalid_vertex(reason: impl Into<String>) -> Self {
+        Self::detected(igraph_error_type_t_IGRAPH_EINVVID, reason)
+    }
+
+    /// An `IGRAPH_EINVEID` error detected by the safe layer before calling igraph.
+    #[track_caller]
+    pub(crate) fn invalid_edge(reason: impl Into<String>) -> Self {
+        Self::detected(igraph_error_type_t_IGRAPH_EINVEID, reason)
+    }
+
+    /// The igraph error code, `None` for errors raised on the Rust side.
+    pub fn code(&self) -> Option<igraph_error_t> {
+        Some(match self {
+            Self::Failure(_) => igraph_error_type_t_IGRAPH_FAILURE,
+            Self::OutOfMemory(_) => igraph_error_type_t_IGRAPH_ENOMEM,
+            Self::Parse(_) => igraph_error_type_t_IGRAPH_PARSEERROR,
+            Self::InvalidValue(_) => igraph_error_type_t_IGRAPH_EINVAL,
+            Self::Exists(_) => igraph_error_type_t_IGRAPH_EXISTS,
+            Self::InvalidVertexId(_) => igraph_error_type_t_IGRAPH_EINVVID,
+            Self::InvalidEdgeId(_) => igraph_error_type_t_IGRAPH_EINVEID,
+            Self::InvalidMode(_) => igraph_error_type_t_IGRAPH_EINVMODE,
+            Self::File(_) => igraph_error_type_t_IGRAPH_EFILE,
+            Self::Unimplemented(_) => igraph_error_type_t_IGRAPH_UNIMPLEMENTED,
+            Self::Interrupted(_) => igraph_error_type_t_IGRAPH_INTERRUPTED,
+            Self::Diverged(_) => igraph_error_type_t_IGRAPH_DIVERGED,
+            Self::Arpack(_) => igraph_error_type_t_IGRAPH_EARPACK,
+            Self::NegativeCycle(_) => igraph_error_type_t_IGRAPH_ENEGCYCLE,
+            Self::Internal(_) => igraph_error_type_t_IGRAPH_EINTERNAL,
+            Self::AttributeCombine(_) => igraph_error_type_t_IGRAPH_EATTRCOMBINE,
+            Self::Overflow(_) => igraph_error_type_t_IGRAPH_EOVERFLOW,
+            Self::Underflow(_) => igraph_error_type_t_IGRAPH_EUNDERFLOW,
+            Self::RandomWalkStuck(_) => igraph_error_type_t_IGRAPH_ERWSTUCK,
+            Self::Stop(_) => igraph_error_type_t_IGRAPH_STOP,
+            Self::Range(_) => igraph_error_type_t_IGRAPH_ERANGE,
+            Self::NoSolution(_) => igraph_error_type_t_IGRAPH_ENOSOL,
+            Self::Unknown(code, _) => *code,
+            Self::Io(_) | Self::VersionMismatch { .. } => return None,
+        })
+    }
+
+    /// The details reported by igraph, `None` for errors raised on the Rust side.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Failure(c)
+            | Self::OutOfMemory(c)
+            | Self::Parse(c)
+            | Self::InvalidValue(c)
+            | Self::Exists(c)
+            | Self::InvalidVertexId(c)
+            | Self::InvalidEdgeId(c)
+            | Self::InvalidMode(c)
+            | Self::File(c)
+            | Self::Unimplemented(c)
+            | Self::Interrupted(c)
+            | Self::Diverged(c)
+            | Self::Arpack(c)
+            | Self::NegativeCycle(c)
+            | Self::Internal(c)
+            | Self::AttributeCombine(c)
+            | Self::Overflow(c)
+            | Self::Underflow(c)
+            | Self::RandomWalkStuck(c)
+            | Self::Stop(c)
+            | Self::Range(c)
+            | Self::NoSolution(c)
+            | Self::Unknown(_, c) => Some(c),
+            Self::Io(_) | Self::VersionMismatch { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for IgraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.context() {
+            Some(c) if c.reason.is_empty() => write!(f, "{}", c.description),
+            Some(c) => write!(
+                f,
+                "{}: {} (at {}:{})",
+                c.description, c.reason, c.file, c.line
+            ),
+            None => match self {
+                Self::Io(e) => write!(f, "{}", e),
+                Self::VersionMismatch { bindings, linked } => write!(
+                    f,
+                    "the bindings were generated for igraph {} but the linked library is igraph {}",
+                    bindings, linked
+                ),
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+impl std::error::Error for IgraphError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for IgraphError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+thread_local! {
+    /// Details of the last error reported by igraph on this thread, consumed by [check].
+    static LAST_ERROR: RefCell<Option<ErrorContext>> = const { RefCell::new(None) };
+}
+
+fn lossy(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() }
+    }
+}
+
+/// The `igraph_error_handler_t` installed by [install_error_handler]: it records
+/// what igraph reports and then, as every custom handler must, releases the
+/// temporary allocations by delegating to `igraph_error_handler_ignore`.
+unsafe extern "C" fn record_error(
+    reason: *const c_char,
+    file: *const c_char,
+    line: c_int,
+    igraph_errno: igraph_error_t,
+) {
+    let context = ErrorContext {
+        description: lossy(unsafe { igraph_strerror(igraph_errno) }),
+        reason: lossy(reason),
+        file: lossy(file),
+        line,
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(context));
+    unsafe { igraph_error_handler_ignore(reason, file, line, igraph_errno) };
+}
+
+/// Records an error raised in a Rust callback invoked by igraph, for [check] to report
+/// once the igraph call fails with `code`, which is returned for the callback to pass on.
+#[track_caller]
+pub(crate) fn record(code: igraph_error_t, reason: impl Into<String>) -> igraph_error_t {
+    let location = std::panic::Location::caller();
+    let context = ErrorContext {
+        description: lossy(unsafe { igraph_strerror(code) }),
+        reason: reason.into(),
+        file: location.file().to_string(),
+        line: location.line() as i32,
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(context));
+    code
+}
+
+/// Replaces igraph's default error handler, which aborts the process, with one
+/// that lets failing calls return their error code to the safe wrappers.
+pub fn install_error_handler() {
+    unsafe {
+        igraph_set_error_handler(Some(record_error));
+    }
+}
+
+/// Makes an igraph call while holding the library, see [thread](crate::thread), and turns
+/// its code into a [Result].
+pub(crate) fn call(f: impl FnOnce() -> igraph_error_t) -> Result<()> {
+    check(crate::thread::serialized(f))
+}
+
+/// Turns the code returned by an igraph function into a [Result].
+pub fn check(code: igraph_error_t) -> Result<()> {
+    if code == igraph_error_type_t_IGRAPH_SUCCESS {
+        return Ok(());
+    }
+    let context = LAST_ERROR
+        .with(|last| last.borrow_mut().take())
+        .unwrap_or_else(|| ErrorContext {
+            description: lossy(unsafe { igraph_strerror(code) }),
+            ..Default::default()
+        });
+    Err(IgraphError::from_code(code, context))
+}
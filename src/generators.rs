@@ -0,0 +1,664 @@
+//! Graph generators, both deterministic and random.
+//!
+//! The generators whose graphs may or may not be directed take the direction as an
+//! [Orientation]: `Graph::ring(5, true, false, true)` builds a plain [Graph], while
+//! `Graph::ring(5, Directed, false, true)` builds a `Graph<Directed>` without a
+//! `TryFrom` conversion.
+//!
+//! [Directed]: crate::Directed
+
+use std::ffi::CStr;
+
+use igraph_sys::{
+    IGRAPH_LOOPS_SW, IGRAPH_MULTI_SW, IGRAPH_SIMPLE_SW, igraph_atlas, igraph_circulant,
+    igraph_cycle_graph, igraph_de_bruijn, igraph_edge_type_sw_t, igraph_erdos_renyi_game_gnm,
+    igraph_extended_chordal_ring, igraph_famous, igraph_full, igraph_full_citation,
+    igraph_full_multipartite, igraph_generalized_petersen, igraph_hexagonal_lattice,
+    igraph_hypercube, igraph_kary_tree, igraph_kautz, igraph_lcf, igraph_path_graph,
+    igraph_regular_tree, igraph_ring, igraph_square_lattice, igraph_star, igraph_star_mode_t,
+    igraph_star_mode_t_IGRAPH_STAR_IN, igraph_star_mode_t_IGRAPH_STAR_MUTUAL,
+    igraph_star_mode_t_IGRAPH_STAR_OUT, igraph_star_mode_t_IGRAPH_STAR_UNDIRECTED,
+    igraph_symmetric_tree, igraph_tree_mode_t, igraph_tree_mode_t_IGRAPH_TREE_IN,
+    igraph_tree_mode_t_IGRAPH_TREE_OUT, igraph_tree_mode_t_IGRAPH_TREE_UNDIRECTED,
+    igraph_triangular_lattice, igraph_turan, igraph_wheel, igraph_wheel_mode_t,
+    igraph_wheel_mode_t_IGRAPH_WHEEL_IN, igraph_wheel_mode_t_IGRAPH_WHEEL_MUTUAL,
+    igraph_wheel_mode_t_IGRAPH_WHEEL_OUT, igraph_wheel_mode_t_IGRAPH_WHEEL_UNDIRECTED,
+};
+
+use crate::{
+    Graph, Matrix, NeighborMode, Result, VertexId,
+    graph::{Directedness, Orientation},
+    vector::{Vector, VectorView},
+};
+
+/// Which kinds of edges a random generator may create, mirroring `igraph_edge_type_sw_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllowedEdgeTypes {
+    /// Neither self-loops nor multi-edges.
+    Simple,
+    /// Self-loops but no multi-edges.
+    Loops,
+    /// Multi-edges but no self-loops.
+    Multi,
+    /// Both self-loops and multi-edges.
+    LoopsAndMulti,
+}
+
+impl AllowedEdgeTypes {
+    pub(crate) fn raw(self) -> igraph_edge_type_sw_t {
+        match self {
+            Self::Simple => IGRAPH_SIMPLE_SW,
+            Self::Loops => IGRAPH_LOOPS_SW,
+            Self::Multi => IGRAPH_MULTI_SW,
+            Self::LoopsAndMulti => IGRAPH_LOOPS_SW | IGRAPH_MULTI_SW,
+        }
+    }
+
+    /// Whether self-loops are allowed.
+    pub fn loops(self) -> bool {
+        matches!(self, Self::Loops | Self::LoopsAndMulti)
+    }
+
+    /// Whether multi-edges are allowed.
+    pub fn multi(self) -> bool {
+        matches!(self, Self::Multi | Self::LoopsAndMulti)
+    }
+}
+
+/// How the edges of a star or a wheel are oriented, mirroring `igraph_star_mode_t` and
+/// `igraph_wheel_mode_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StarMode {
+    /// Directed edges from the center to the other vertices.
+    Out,
+    /// Directed edges from the other vertices to the center.
+    In,
+    /// Undirected edges.
+    Undirected,
+    /// Directed edges in both directions.
+    Mutual,
+}
+
+impl StarMode {
+    pub(crate) fn raw(self) -> igraph_star_mode_t {
+        match self {
+            Self::Out => igraph_star_mode_t_IGRAPH_STAR_OUT,
+            Self::In => igraph_star_mode_t_IGRAPH_STAR_IN,
+            Self::Undirected => igraph_star_mode_t_IGRAPH_STAR_UNDIRECTED,
+            Self::Mutual => igraph_star_mode_t_IGRAPH_STAR_MUTUAL,
+        }
+    }
+
+    pub(crate) fn raw_wheel(self) -> igraph_wheel_mode_t {
+        match self {
+            Self::Out => igraph_wheel_mode_t_IGRAPH_WHEEL_OUT,
+            Self::In => igraph_wheel_mode_t_IGRAPH_WHEEL_IN,
+            Self::Undirected => igraph_wheel_mode_t_IGRAPH_WHEEL_UNDIRECTED,
+            Self::Mutual => igraph_wheel_mode_t_IGRAPH_WHEEL_MUTUAL,
+        }
+    }
+}
+
+/// How the edges of a tree are oriented, mirroring `igraph_tree_mode_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TreeMode {
+    /// Directed edges from parents to children.
+    Out,
+    /// Directed edges from children to parents.
+    In,
+    /// Undirected edges.
+    Undirected,
+}
+
+impl TreeMode {
+    pub(crate) fn raw(self) -> igraph_tree_mode_t {
+        match self {
+            Self::Out => igraph_tree_mode_t_IGRAPH_TREE_OUT,
+            Self::In => igraph_tree_mode_t_IGRAPH_TREE_IN,
+            Self::Undirected => igraph_tree_mode_t_IGRAPH_TREE_UNDIRECTED,
+        }
+    }
+}
+
+/// The named graphs built by [Graph::famous], mirroring the names `igraph_famous` knows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Famous {
+    /// The bull graph: a triangle with two pendant edges.
+    Bull,
+    /// The Chvátal graph, the smallest triangle-free 4-regular 4-chromatic graph.
+    Chvatal,
+    /// The Coxeter graph, a non-Hamiltonian cubic symmetric graph on 28 vertices.
+    Coxeter,
+    /// The skeleton of the cube.
+    Cubical,
+    /// The diamond graph: the complete graph on four vertices minus an edge.
+    Diamond,
+    /// The skeleton of the dodecahedron.
+    Dodecahedral,
+    /// The Folkman graph, the smallest semisymmetric graph.
+    Folkman,
+    /// The Franklin graph, a cubic graph on 12 vertices.
+    Franklin,
+    /// The Frucht graph, the smallest cubic graph without nontrivial automorphisms.
+    Frucht,
+    /// The Grötzsch graph, the smallest triangle-free 4-chromatic graph.
+    Grotzsch,
+    /// The Heawood graph, the incidence graph of the Fano plane.
+    Heawood,
+    /// The Herschel graph, the smallest non-Hamiltonian polyhedral graph.
+    Herschel,
+    /// The house graph: a square with a triangular roof.
+    House,
+    /// The house graph with both diagonals of the square.
+    HouseX,
+    /// The skeleton of the icosahedron.
+    Icosahedral,
+    /// Krackhardt's kite, a social network in which three centralities disagree.
+    KrackhardtKite,
+    /// The Levi graph, the incidence graph of the Cremona–Richmond configuration.
+    Levi,
+    /// The McGee graph, the unique (3,7)-cage.
+    McGee,
+    /// The Meredith graph, a 4-regular 4-connected non-Hamiltonian graph.
+    Meredith,
+    /// A connected graph on 16 vertices without a perfect matching.
+    NoPerfectMatching,
+    /// The nine forbidden induced subgraphs of line graphs, as one disconnected graph.
+    Nonline,
+    /// The skeleton of the octahedron.
+    Octahedral,
+    /// The Petersen graph.
+    Petersen,
+    /// The Robertson graph, the unique (4,5)-cage.
+    Robertson,
+    /// The smallest graph whose automorphism group is cyclic.
+    SmallestCyclicGroup,
+    /// The skeleton of the tetrahedron, i.e. the complete graph on four vertices.
+    Tetrahedral,
+    /// The Thomassen graph, the smallest hypotraceable graph.
+    Thomassen,
+    /// The Tutte graph, a non-Hamiltonian cubic polyhedral graph.
+    Tutte,
+    /// A graph on 12 vertices with a unique 3-coloring.
+    Uniquely3Colorable,
+    /// The Walther graph, a bipartite planar graph on 25 vertices.
+    Walther,
+    /// Zachary's karate club, the social network of 34 club members.
+    Zachary,
+}
+
+impl Famous {
+    /// The name `igraph_famous` knows the graph by.
+    pub fn name(self) -> &'static CStr {
+        match self {
+            Self::Bull => c"Bull",
+            Self::Chvatal => c"Chvatal",
+            Self::Coxeter => c"Coxeter",
+            Self::Cubical => c"Cubical",
+            Self::Diamond => c"Diamond",
+            Self::Dodecahedral => c"Dodecahedral",
+            Self::Folkman => c"Folkman",
+            Self::Franklin => c"Franklin",
+            Self::Frucht => c"Frucht",
+            Self::Grotzsch => c"Grotzsch",
+            Self::Heawood => c"Heawood",
+            Self::Herschel => c"Herschel",
+            Self::House => c"House",
+            Self::HouseX => c"HouseX",
+            Self::Icosahedral => c"Icosahedral",
+            Self::KrackhardtKite => c"Krackhardt_Kite",
+            Self::Levi => c"Levi",
+            Self::McGee => c"McGee",
+            Self::Meredith => c"Meredith",
+            Self::NoPerfectMatching => c"Noperfectmatching",
+            Self::Nonline => c"Nonline",
+            Self::Octahedral => c"Octahedral",
+            Self::Petersen => c"Petersen",
+            Self::Robertson => c"Robertson",
+            Self::SmallestCyclicGroup => c"Smallestcyclicgroup",
+            Self::Tetrahedral => c"Tetrahedral",
+            Self::Thomassen => c"Thomassen",
+            Self::Tutte => c"Tutte",
+            Self::Uniquely3Colorable => c"Uniquely3colorable",
+            Self::Walther => c"Walther",
+            Self::Zachary => c"Zachary",
+        }
+    }
+}
+
+/// Converts the sizes a generator takes into the `igraph_int_t`s of an igraph vector.
+pub(crate) fn sizes(sizes: &[usize]) -> Vec<i64> {
+    sizes.iter().map(|&size| size as i64).collect()
+}
+
+impl Graph {
+    /// A named graph from the literature, binding
+    /// [igraph_famous](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_famous).
+    pub fn famous(graph: Famous) -> Result<Self> {
+        Self::init_with(|inner| unsafe { igraph_famous(inner, graph.name().as_ptr()) })
+    }
+
+    /// The graph numbered `index` in "An Atlas of Graphs" by Read and Wilson, which lists
+    /// the 1253 undirected graphs with at most seven vertices, binding
+    /// [igraph_atlas](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_atlas).
+    pub fn atlas(index: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_atlas(graph, index as i64) })
+    }
+
+    /// A star with `num_vertices` vertices around `center`, binding
+    /// [igraph_star](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_star).
+    pub fn star(num_vertices: usize, mode: StarMode, center: impl Into<VertexId>) -> Result<Self> {
+        let center = center.into();
+        Self::init_with(|graph| unsafe {
+            igraph_star(graph, num_vertices as i64, mode.raw(), center.get())
+        })
+    }
+
+    /// A wheel, i.e. a star whose outer vertices also form a cycle, binding
+    /// [igraph_wheel](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_wheel).
+    pub fn wheel(num_vertices: usize, mode: StarMode, center: impl Into<VertexId>) -> Result<Self> {
+        let center = center.into();
+        Self::init_with(|graph| unsafe {
+            igraph_wheel(graph, num_vertices as i64, mode.raw_wheel(), center.get())
+        })
+    }
+
+    /// A tree with `num_vertices` vertices, filled level by level, in which every vertex
+    /// has up to `children` children, binding
+    /// [igraph_kary_tree](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_kary_tree).
+    pub fn kary_tree(num_vertices: usize, children: usize, mode: TreeMode) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_kary_tree(graph, num_vertices as i64, children as i64, mode.raw())
+        })
+    }
+
+    /// A tree whose vertices at depth `d` have `branches[d]` children, binding
+    /// [igraph_symmetric_tree](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_symmetric_tree).
+    pub fn symmetric_tree(branches: &[usize], mode: TreeMode) -> Result<Self> {
+        let branches = sizes(branches);
+        Self::init_with(|graph| unsafe {
+            igraph_symmetric_tree(graph, VectorView::new(&branches).as_ptr(), mode.raw())
+        })
+    }
+
+    /// A tree of the given `height` whose inner vertices all have degree `degree`, binding
+    /// [igraph_regular_tree](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_regular_tree).
+    pub fn regular_tree(height: usize, degree: usize, mode: TreeMode) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_regular_tree(graph, height as i64, degree as i64, mode.raw())
+        })
+    }
+
+    /// The Turán graph, the complete `parts`-partite graph on `num_vertices` vertices with
+    /// parts as equal as possible, and the part of each vertex, binding
+    /// [igraph_turan](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_turan).
+    pub fn turan(num_vertices: usize, parts: usize) -> Result<(Self, Vec<i64>)> {
+        let mut types = Vector::<i64>::new()?;
+        let graph = Self::init_with(|graph| unsafe {
+            igraph_turan(graph, types.as_mut_ptr(), num_vertices as i64, parts as i64)
+        })?;
+        Ok((graph, types.into()))
+    }
+
+    /// The generalized Petersen graph `GP(n, k)`, with `2n` vertices, binding
+    /// [igraph_generalized_petersen](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_generalized_petersen).
+    pub fn generalized_petersen(n: usize, k: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_generalized_petersen(graph, n as i64, k as i64) })
+    }
+
+    /// The De Bruijn graph on the words of length `n` over `m` letters, binding
+    /// [igraph_de_bruijn](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_de_bruijn).
+    pub fn de_bruijn(m: usize, n: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_de_bruijn(graph, m as i64, n as i64) })
+    }
+
+    /// The Kautz graph on the words of length `n + 1` over `m + 1` letters without two
+    /// equal consecutive letters, binding
+    /// [igraph_kautz](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_kautz).
+    pub fn kautz(m: usize, n: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_kautz(graph, m as i64, n as i64) })
+    }
+
+    /// A graph in LCF notation: a ring on `num_vertices` vertices plus, for the `i`-th
+    /// vertex, a chord to `i + shifts[i mod len]`, the shifts being repeated `repeats`
+    /// times, binding
+    /// [igraph_lcf](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_lcf).
+    pub fn lcf(num_vertices: usize, shifts: &[i64], repeats: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_lcf(
+                graph,
+                num_vertices as i64,
+                VectorView::new(shifts).as_ptr(),
+                repeats as i64,
+            )
+        })
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    /// In the `G(n, m)` Erdős-Rényi model, a graph with `n` vertices and `m` edges is generated uniformly at random;
+    /// for the sake of clarity, it binds the [igraph_erdos_renyi_game_gnm](https://igraph.org/c/html/latest/igraph-Games.html#igraph_erdos_renyi_game_gnm) function.
+    pub fn erdos_renyi_game_gnm(
+        num_vertices: usize,
+        num_edges: usize,
+        directed: impl Orientation<Marker = D>,
+        mode: AllowedEdgeTypes,
+        edge_attr: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_erdos_renyi_game_gnm(
+                graph,
+                num_vertices as i64,
+                num_edges as i64,
+                directed.is_directed(),
+                mode.raw(),
+                edge_attr,
+            )
+        })
+    }
+
+    /// The `dim`-dimensional hypercube, with `2^dim` vertices, binding
+    /// [igraph_hypercube](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_hypercube).
+    pub fn hypercube(dim: usize, directed: impl Orientation<Marker = D>) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_hypercube(graph, dim as i64, directed.is_directed())
+        })
+    }
+
+    /// A ring, or a path unless `circular`, binding
+    /// [igraph_ring](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_ring);
+    /// directed graphs have edges in both directions if `mutual`.
+    pub fn ring(
+        num_vertices: usize,
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+        circular: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_ring(
+                graph,
+                num_vertices as i64,
+                directed.is_directed(),
+                mutual,
+                circular,
+            )
+        })
+    }
+
+    /// A path through `num_vertices` vertices, binding
+    /// [igraph_path_graph](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_path_graph);
+    /// directed graphs have edges in both directions if `mutual`.
+    pub fn path_graph(
+        num_vertices: usize,
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_path_graph(graph, num_vertices as i64, directed.is_directed(), mutual)
+        })
+    }
+
+    /// A cycle through `num_vertices` vertices, binding
+    /// [igraph_cycle_graph](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_cycle_graph);
+    /// directed graphs have edges in both directions if `mutual`.
+    pub fn cycle_graph(
+        num_vertices: usize,
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_cycle_graph(graph, num_vertices as i64, directed.is_directed(), mutual)
+        })
+    }
+
+    /// The complete graph on `num_vertices` vertices, with a self-loop on each if `loops`,
+    /// binding [igraph_full](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_full).
+    pub fn full(
+        num_vertices: usize,
+        directed: impl Orientation<Marker = D>,
+        loops: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_full(graph, num_vertices as i64, directed.is_directed(), loops)
+        })
+    }
+
+    /// The complete multipartite graph with parts of the given sizes, and the part of each
+    /// vertex, binding
+    /// [igraph_full_multipartite](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_full_multipartite).
+    ///
+    /// In directed graphs, `mode` tells whether edges go from lower-numbered parts to
+    /// higher-numbered ones ([NeighborMode::Out]), the other way round, or both.
+    pub fn full_multipartite(
+        part_sizes: &[usize],
+        directed: impl Orientation<Marker = D>,
+        mode: NeighborMode,
+    ) -> Result<(Self, Vec<i64>)> {
+        let part_sizes = sizes(part_sizes);
+        let mut types = Vector::<i64>::new()?;
+        let graph = Self::init_with(|graph| unsafe {
+            igraph_full_multipartite(
+                graph,
+                types.as_mut_ptr(),
+                VectorView::new(&part_sizes).as_ptr(),
+                directed.is_directed(),
+                mode.raw(),
+            )
+        })?;
+        Ok((graph, types.into()))
+    }
+
+    /// The full citation graph, in which every vertex is connected to all the vertices
+    /// with a lower id, binding
+    /// [igraph_full_citation](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_full_citation).
+    pub fn full_citation(
+        num_vertices: usize,
+        directed: impl Orientation<Marker = D>,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_full_citation(graph, num_vertices as i64, directed.is_directed())
+        })
+    }
+
+    /// A square lattice with the given size along each dimension, connecting the vertices
+    /// within `neighbors` steps of each other, binding
+    /// [igraph_square_lattice](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_square_lattice).
+    ///
+    /// `periodic` tells for each dimension whether it wraps around; directed graphs have
+    /// edges in both directions if `mutual`.
+    pub fn square_lattice(
+        dims: &[usize],
+        neighbors: usize,
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+        periodic: &[bool],
+    ) -> Result<Self> {
+        let dims = sizes(dims);
+        Self::init_with(|graph| unsafe {
+            igraph_square_lattice(
+                graph,
+                VectorView::new(&dims).as_ptr(),
+                neighbors as i64,
+                directed.is_directed(),
+                mutual,
+                VectorView::new(periodic).as_ptr(),
+            )
+        })
+    }
+
+    /// A triangular lattice, binding
+    /// [igraph_triangular_lattice](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_triangular_lattice);
+    /// see there how one, two or three `dims` shape it as a triangle, a quasi-rectangle or
+    /// a hexagon.
+    pub fn triangular_lattice(
+        dims: &[usize],
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+    ) -> Result<Self> {
+        let dims = sizes(dims);
+        Self::init_with(|graph| unsafe {
+            igraph_triangular_lattice(
+                graph,
+                VectorView::new(&dims).as_ptr(),
+                directed.is_directed(),
+                mutual,
+            )
+        })
+    }
+
+    /// A hexagonal lattice, binding
+    /// [igraph_hexagonal_lattice](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_hexagonal_lattice);
+    /// see there how one, two or three `dims` shape it as a triangle, a quasi-rectangle or
+    /// a hexagon.
+    pub fn hexagonal_lattice(
+        dims: &[usize],
+        directed: impl Orientation<Marker = D>,
+        mutual: bool,
+    ) -> Result<Self> {
+        let dims = sizes(dims);
+        Self::init_with(|graph| unsafe {
+            igraph_hexagonal_lattice(
+                graph,
+                VectorView::new(&dims).as_ptr(),
+                directed.is_directed(),
+                mutual,
+            )
+        })
+    }
+
+    /// The circulant graph on `num_vertices` vertices, connecting every vertex `i` to
+    /// `i + shift` modulo `num_vertices` for each of the `shifts`, binding
+    /// [igraph_circulant](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_circulant).
+    pub fn circulant(
+        num_vertices: usize,
+        shifts: &[i64],
+        directed: impl Orientation<Marker = D>,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_circulant(
+                graph,
+                num_vertices as i64,
+                VectorView::new(shifts).as_ptr(),
+                directed.is_directed(),
+            )
+        })
+    }
+
+    /// An extended chordal ring on `num_vertices` vertices, binding
+    /// [igraph_extended_chordal_ring](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_extended_chordal_ring):
+    /// on top of the ring, vertex `i` is connected to `i + w[(i mod p, j)]` for every
+    /// column `j` of the `p`-row matrix `w`.
+    pub fn extended_chordal_ring(
+        num_vertices: usize,
+        w: &Matrix<i64>,
+        directed: impl Orientation<Marker = D>,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_extended_chordal_ring(
+                graph,
+                num_vertices as i64,
+                w.as_ptr(),
+                directed.is_directed(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Directed, Undirected};
+
+    fn shape(graph: &Graph) -> (usize, usize) {
+        (graph.num_vertices(), graph.num_edges())
+    }
+
+    #[test]
+    fn test_deterministic_generators() {
+        Graph::setup().unwrap();
+        assert_eq!(
+            shape(&Graph::star(5, StarMode::Undirected, 0).unwrap()),
+            (5, 4)
+        );
+        assert_eq!(
+            shape(&Graph::wheel(5, StarMode::Mutual, 0).unwrap()),
+            (5, 16)
+        );
+        assert_eq!(shape(&Graph::hypercube(3, false).unwrap()), (8, 12));
+        assert_eq!(shape(&Graph::ring(5, false, false, false).unwrap()), (5, 4));
+        assert_eq!(shape(&Graph::path_graph(5, true, true).unwrap()), (5, 8));
+        assert_eq!(shape(&Graph::cycle_graph(5, false, false).unwrap()), (5, 5));
+        assert_eq!(
+            shape(&Graph::kary_tree(7, 2, TreeMode::Out).unwrap()),
+            (7, 6)
+        );
+        assert_eq!(
+            shape(&Graph::symmetric_tree(&[2, 3], TreeMode::In).unwrap()),
+            (9, 8)
+        );
+        assert_eq!(
+            shape(&Graph::regular_tree(2, 3, TreeMode::Undirected).unwrap()),
+            (10, 9)
+        );
+        assert_eq!(shape(&Graph::full(4, false, false).unwrap()), (4, 6));
+        assert_eq!(shape(&Graph::full_citation(4, true).unwrap()), (4, 6));
+        assert_eq!(
+            shape(&Graph::square_lattice(&[3, 3], 1, false, false, &[false, false]).unwrap()),
+            (9, 12)
+        );
+        assert_eq!(
+            shape(&Graph::circulant(6, &[1, 2], false).unwrap()),
+            (6, 12)
+        );
+        assert_eq!(shape(&Graph::generalized_petersen(5, 2).unwrap()), (10, 15));
+        assert_eq!(shape(&Graph::de_bruijn(2, 3).unwrap()), (8, 16));
+        assert_eq!(shape(&Graph::kautz(2, 1).unwrap()), (6, 12));
+        // The Heawood graph.
+        assert_eq!(shape(&Graph::lcf(14, &[5, -5], 7).unwrap()), (14, 21));
+
+        let w = Matrix::from_rows(&[vec![3]]).unwrap();
+        assert_eq!(
+            shape(&Graph::extended_chordal_ring(6, &w, false).unwrap()),
+            (6, 12)
+        );
+
+        let (graph, parts) =
+            Graph::full_multipartite(&[1, 2, 3], false, NeighborMode::All).unwrap();
+        assert_eq!(shape(&graph), (6, 11));
+        assert_eq!(parts, [0, 1, 1, 2, 2, 2]);
+        let (graph, parts) = Graph::turan(5, 2).unwrap();
+        assert_eq!(shape(&graph), (5, 6));
+        assert_eq!(parts.len(), 5);
+
+        let ring: Graph<Directed> = Graph::ring(4, Directed, false, true).unwrap();
+        assert_eq!((ring.num_vertices(), ring.num_edges()), (4, 4));
+        let full = Graph::<Undirected>::full(4, Undirected, false).unwrap();
+        assert_eq!((full.num_vertices(), full.num_edges()), (4, 6));
+
+        // A triangle with three vertices per side, split into four triangles.
+        assert_eq!(
+            shape(&Graph::triangular_lattice(&[3], false, false).unwrap()),
+            (6, 9)
+        );
+        // A single hexagon.
+        assert_eq!(
+            shape(&Graph::hexagonal_lattice(&[1, 1], false, false).unwrap()),
+            (6, 6)
+        );
+    }
+
+    #[test]
+    fn test_famous_and_atlas() {
+        Graph::setup().unwrap();
+        assert_eq!(shape(&Graph::famous(Famous::Petersen).unwrap()), (10, 15));
+        assert_eq!(shape(&Graph::famous(Famous::Zachary).unwrap()), (34, 78));
+        assert_eq!(shape(&Graph::famous(Famous::Tutte).unwrap()), (46, 69));
+        assert_eq!(
+            shape(&Graph::famous(Famous::KrackhardtKite).unwrap()),
+            (10, 18)
+        );
+        assert_eq!(shape(&Graph::atlas(0).unwrap()), (0, 0));
+        assert_eq!(shape(&Graph::atlas(7).unwrap()), (3, 3));
+        assert!(Graph::atlas(1253).is_err());
+    }
+}
@@ -0,0 +1,521 @@
+//! The safe owner of an initialized `igraph_t`.
+//!
+//! A [Graph] carries its directedness in its type parameter: `Graph<Directed>` and
+//! `Graph<Undirected>` are known to be directed or undirected, and algorithms that only
+//! make sense on directed graphs are only available on `Graph<Directed>`. Plain `Graph`,
+//! i.e. `Graph<Dynamic>`, leaves it to [Graph::is_directed], as igraph does; it converts
+//! to the typed graphs with `TryFrom`, and [Graph::into_directed] and
+//! [Graph::into_undirected] convert any graph with `igraph_to_directed` and
+//! `igraph_to_undirected`.
+//!
+//! Weighted algorithms take `weights: Option<&[f64]>`, one weight per edge indexed by
+//! [EdgeId], or `None` for the unweighted variant. igraph appends new edges after the
+//! existing ones and keeps the order of the remaining edges on deletion, so a weight
+//! vector stays in sync by pushing the weights of added edges and dropping those of
+//! deleted ones; a vector whose length differs from [Graph::num_edges] is rejected with
+//! [IgraphError::InvalidValue] before igraph is called.
+
+use std::{fmt, marker::PhantomData, mem};
+
+use igraph_sys::{
+    igraph_add_edge, igraph_add_edges, igraph_add_vertices, igraph_copy, igraph_delete_edges,
+    igraph_delete_vertices, igraph_destroy, igraph_ecount, igraph_empty, igraph_error_t,
+    igraph_induced_subgraph, igraph_is_directed, igraph_permute_vertices,
+    igraph_subgraph_implementation_t_IGRAPH_SUBGRAPH_AUTO, igraph_t, igraph_to_directed,
+    igraph_to_directed_t, igraph_to_directed_t_IGRAPH_TO_DIRECTED_ACYCLIC,
+    igraph_to_directed_t_IGRAPH_TO_DIRECTED_ARBITRARY,
+    igraph_to_directed_t_IGRAPH_TO_DIRECTED_MUTUAL, igraph_to_directed_t_IGRAPH_TO_DIRECTED_RANDOM,
+    igraph_to_undirected, igraph_to_undirected_t,
+    igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_COLLAPSE,
+    igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_EACH,
+    igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_MUTUAL, igraph_vcount, igraph_vector_int_t,
+    igraph_vector_t,
+};
+
+use crate::{
+    EdgeId, EdgeSelector, IgraphError, Result, VertexId, VertexSelector,
+    attributes::{self, Table},
+    error::{call, check},
+    thread,
+    vector::{Vector, VectorView},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The directedness of a [Graph], as fixed by its type parameter.
+pub trait Directedness: sealed::Sealed {
+    /// Whether graphs of this type are directed, `None` if only known at runtime.
+    const DIRECTED: Option<bool>;
+}
+
+/// Marks graphs whose edges are directed; passed to a generator, asks for such a graph.
+#[derive(Debug, Clone, Copy)]
+pub struct Directed;
+
+/// Marks graphs whose edges are undirected; passed to a generator, asks for such a graph.
+#[derive(Debug, Clone, Copy)]
+pub struct Undirected;
+
+/// Marks graphs whose directedness is only known at runtime, the default.
+#[derive(Debug)]
+pub enum Dynamic {}
+
+impl sealed::Sealed for Directed {}
+impl sealed::Sealed for Undirected {}
+impl sealed::Sealed for Dynamic {}
+impl sealed::Sealed for bool {}
+
+impl Directedness for Directed {
+    const DIRECTED: Option<bool> = Some(true);
+}
+
+impl Directedness for Undirected {
+    const DIRECTED: Option<bool> = Some(false);
+}
+
+impl Directedness for Dynamic {
+    const DIRECTED: Option<bool> = None;
+}
+
+/// The `directed` argument of generators, which also fixes the type of the graph they
+/// return: a `bool` gives a plain [Graph] directed or not at runtime, the [Directed] and
+/// [Undirected] markers a `Graph<Directed>` or a `Graph<Undirected>`.
+pub trait Orientation: sealed::Sealed + Copy {
+    /// The directedness of the graphs built with this argument.
+    type Marker: Directedness;
+
+    fn is_directed(self) -> bool;
+}
+
+impl Orientation for bool {
+    type Marker = Dynamic;
+
+    fn is_directed(self) -> bool {
+        self
+    }
+}
+
+impl Orientation for Directed {
+    type Marker = Directed;
+
+    fn is_directed(self) -> bool {
+        true
+    }
+}
+
+impl Orientation for Undirected {
+    type Marker = Undirected;
+
+    fn is_directed(self) -> bool {
+        false
+    }
+}
+
+/// How [Graph::into_directed] turns undirected edges into directed ones, mirroring
+/// `igraph_to_directed_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToDirected {
+    /// One directed edge per undirected edge, in an arbitrary direction.
+    Arbitrary,
+    /// Two directed edges per undirected edge, one in each direction.
+    Mutual,
+    /// One directed edge per undirected edge, in a random direction.
+    Random,
+    /// One directed edge per undirected edge, from the lower vertex id to the higher one,
+    /// which makes the graph acyclic unless it has self-loops.
+    Acyclic,
+}
+
+impl ToDirected {
+    pub(crate) fn raw(self) -> igraph_to_directed_t {
+        match self {
+            Self::Arbitrary => igraph_to_directed_t_IGRAPH_TO_DIRECTED_ARBITRARY,
+            Self::Mutual => igraph_to_directed_t_IGRAPH_TO_DIRECTED_MUTUAL,
+            Self::Random => igraph_to_directed_t_IGRAPH_TO_DIRECTED_RANDOM,
+            Self::Acyclic => igraph_to_directed_t_IGRAPH_TO_DIRECTED_ACYCLIC,
+        }
+    }
+}
+
+/// How [Graph::into_undirected] turns directed edges into undirected ones, mirroring
+/// `igraph_to_undirected_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToUndirected {
+    /// One undirected edge per directed edge, keeping multi-edges.
+    Each,
+    /// One undirected edge per pair of connected vertices.
+    Collapse,
+    /// One undirected edge per pair of mutually connected vertices; for each pair, as
+    /// many edges as there are edge pairs going in both directions.
+    Mutual,
+}
+
+impl ToUndirected {
+    pub(crate) fn raw(self) -> igraph_to_undirected_t {
+        match self {
+            Self::Each => igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_EACH,
+            Self::Collapse => igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_COLLAPSE,
+            Self::Mutual => igraph_to_undirected_t_IGRAPH_TO_UNDIRECTED_MUTUAL,
+        }
+    }
+}
+
+/// Edge weights checked against a graph by [Graph::check_weights].
+pub(crate) struct Weights<'w>(Option<VectorView<'w, f64>>);
+
+impl Weights<'_> {
+    /// The weights as igraph takes them, null for unweighted calls.
+    pub(crate) fn as_ptr(&self) -> *const igraph_vector_t {
+        self.0.as_ref().map_or(std::ptr::null(), VectorView::as_ptr)
+    }
+}
+
+/// A graph owned by Rust: the wrapped `igraph_t` is always initialized and is
+/// released with `igraph_destroy` when the `Graph` is dropped.
+pub struct Graph<D: Directedness = Dynamic> {
+    inner: igraph_t,
+    /// The attribute table `inner.attr` belongs to, installed whenever igraph sees the graph.
+    pub(crate) table: Option<Table>,
+    directedness: PhantomData<D>,
+}
+
+// SAFETY: an `igraph_t` owns its memory and has no affinity to the thread that created
+// it. Its attribute table is installed by `as_ptr` on whichever thread uses it; the
+// handlers and default generator it is used with are either per thread or, without
+// thread-local storage, shared by threads that take turns: the safe layer holds
+// `thread::lock` during every igraph call, including the one in `drop`.
+//
+// `Graph` is not `Sync`: igraph caches structural properties inside the `igraph_t` and
+// updates the cache through `const` pointers, so shared references cannot be used from
+// several threads at once; share an `Arc<Mutex<Graph>>` instead.
+unsafe impl<D: Directedness> Send for Graph<D> {}
+
+impl<D: Directedness> Drop for Graph<D> {
+    fn drop(&mut self) {
+        thread::serialized(|| unsafe { igraph_destroy(self.as_mut_ptr()) });
+    }
+}
+
+impl Graph {
+    /// Initializes the library and installs the error handler that lets
+    /// failing calls surface as [IgraphError] instead of aborting the process.
+    ///
+    /// This happens automatically before the first igraph object is created, once per
+    /// thread, or once per process when igraph lacks thread-local storage; see [thread].
+    ///
+    /// Refuses to proceed, with [IgraphError::VersionMismatch],
+    /// when the linked libigraph does not match the version the bindings were generated from.
+    ///
+    /// It also installs the attribute table [enabled](crate::attributes) on this thread,
+    /// the one raw graphs passed to [Graph::from_raw] must have been created with.
+    pub fn setup() -> Result<()> {
+        thread::setup()?;
+        attributes::install(attributes::enabled());
+        Ok(())
+    }
+
+    pub fn new(num_vertices: usize, directed: bool) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_empty(graph, num_vertices as i64, directed) })
+    }
+
+    /// Takes ownership of a raw graph; convert it with `TryFrom` for a typed directedness.
+    ///
+    /// # Safety
+    ///
+    /// `graph` must have been initialized by igraph and must not be destroyed elsewhere.
+    /// Its attributes, if any, must belong to the attribute table enabled on this thread,
+    /// as they do for graphs created by igraph right after [Graph::setup].
+    pub unsafe fn from_raw(graph: igraph_t) -> Self {
+        Self {
+            inner: graph,
+            table: attributes::enabled(),
+            directedness: PhantomData,
+        }
+    }
+}
+
+impl Graph<Directed> {
+    /// A directed graph with `num_vertices` vertices and no edges.
+    pub fn empty(num_vertices: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_empty(graph, num_vertices as i64, true) })
+    }
+}
+
+impl Graph<Undirected> {
+    /// An undirected graph with `num_vertices` vertices and no edges.
+    pub fn empty(num_vertices: usize) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_empty(graph, num_vertices as i64, false) })
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    /// Runs `init` on a zeroed `igraph_t`, taking ownership only if igraph initialized it
+    /// successfully with the directedness `D` requires.
+    pub(crate) fn init_with(init: impl FnOnce(*mut igraph_t) -> igraph_error_t) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<igraph_t>() };
+        let table = thread::serialized(|| {
+            // Graphs passed to `init` install their own table when their pointer is taken,
+            // so a copy gets the table of its source.
+            attributes::install(attributes::enabled());
+            check(init(&mut inner)).map(|()| attributes::installed())
+        })?;
+        let graph = Self {
+            inner,
+            table,
+            directedness: PhantomData,
+        };
+        match D::DIRECTED {
+            Some(directed) if directed != graph.is_directed() => {
+                Err(IgraphError::invalid_value(if directed {
+                    "igraph created an undirected graph where a directed one was expected"
+                } else {
+                    "igraph created a directed graph where an undirected one was expected"
+                }))
+            }
+            _ => Ok(graph),
+        }
+    }
+
+    /// Gives up ownership of the raw graph, which the caller must eventually pass to
+    /// `igraph_destroy` with the attribute table of the graph installed.
+    pub fn into_raw(self) -> igraph_t {
+        let this = mem::ManuallyDrop::new(self);
+        unsafe { std::ptr::read(&this.inner) }
+    }
+
+    /// The same graph with another directedness marker, which the caller must ensure holds.
+    fn retype<E: Directedness>(self) -> Graph<E> {
+        let table = self.table;
+        Graph {
+            inner: self.into_raw(),
+            table,
+            directedness: PhantomData,
+        }
+    }
+
+    /// Forgets the directedness of the type, leaving it to [Graph::is_directed].
+    pub fn into_dynamic(self) -> Graph {
+        self.retype()
+    }
+
+    /// Makes the graph directed with `igraph_to_directed`, leaving directed graphs as they are.
+    pub fn into_directed(self, mode: ToDirected) -> Result<Graph<Directed>> {
+        let mut graph = self.into_dynamic();
+        call(|| unsafe { igraph_to_directed(graph.as_mut_ptr(), mode.raw()) })?;
+        Ok(graph.retype())
+    }
+
+    /// Makes the graph undirected with `igraph_to_undirected`, leaving undirected graphs as
+    /// they are. Edge attributes are dropped when edges are merged.
+    pub fn into_undirected(self, mode: ToUndirected) -> Result<Graph<Undirected>> {
+        let mut graph = self.into_dynamic();
+        call(|| unsafe { igraph_to_undirected(graph.as_mut_ptr(), mode.raw(), std::ptr::null()) })?;
+        Ok(graph.retype())
+    }
+
+    /// A pointer to the wrapped `igraph_t`, for calling igraph functions not covered by this crate.
+    ///
+    /// Installs the attribute table the graph was created with, which igraph functions
+    /// called on the pointer expect; take the pointer again after using another graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph was moved to a thread on which igraph cannot be set up.
+    pub fn as_ptr(&self) -> *const igraph_t {
+        thread::setup_moved();
+        attributes::install(self.table);
+        &self.inner
+    }
+
+    /// A mutable pointer to the wrapped `igraph_t`, for calling igraph functions not covered by this crate.
+    ///
+    /// Installs the attribute table the graph was created with, and panics, like
+    /// [Graph::as_ptr].
+    pub fn as_mut_ptr(&mut self) -> *mut igraph_t {
+        thread::setup_moved();
+        attributes::install(self.table);
+        &mut self.inner
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        unsafe { igraph_vcount(self.as_ptr()) as usize }
+    }
+
+    pub fn num_edges(&self) -> usize {
+        unsafe { igraph_ecount(self.as_ptr()) as usize }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        D::DIRECTED.unwrap_or_else(|| unsafe { igraph_is_directed(self.as_ptr()) })
+    }
+
+    pub fn add_vertices(&mut self, n: usize) -> Result<()> {
+        call(|| unsafe { igraph_add_vertices(self.as_mut_ptr(), n as i64, std::ptr::null()) })
+    }
+
+    pub fn add_edge(&mut self, from: impl Into<VertexId>, to: impl Into<VertexId>) -> Result<()> {
+        let from = self.check_vertex(from.into())?;
+        let to = self.check_vertex(to.into())?;
+        call(|| unsafe { igraph_add_edge(self.as_mut_ptr(), from.get(), to.get()) })
+    }
+
+    pub fn add_edges_from_slice<V: Into<VertexId> + Copy>(
+        &mut self,
+        edges_slice: &[(V, V)],
+    ) -> Result<()> {
+        let edges = edges_slice
+            .iter()
+            .flat_map(|&(from, to)| [from.into(), to.into()])
+            .map(|v| self.check_vertex(v).map(VertexId::get))
+            .collect::<Result<Vec<i64>>>()?;
+        self.add_edges_from_raw(VectorView::new(&edges).as_ptr())
+    }
+
+    /// Adds the edges between consecutive pairs of vertex ids in `edges`.
+    pub fn add_edges_from_vector(&mut self, edges: &Vector<i64>) -> Result<()> {
+        for &v in edges.iter() {
+            self.check_vertex(VertexId::try_from(v)?)?;
+        }
+        self.add_edges_from_raw(edges.as_ptr())
+    }
+
+    fn add_edges_from_raw(&mut self, edges: *const igraph_vector_int_t) -> Result<()> {
+        call(|| unsafe { igraph_add_edges(self.as_mut_ptr(), edges, std::ptr::null()) })
+    }
+
+    /// Copies the graph, attributes included, with `igraph_copy`.
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::init_with(|graph| unsafe { igraph_copy(graph, self.as_ptr()) })
+    }
+
+    /// Fails with [IgraphError::InvalidVertexId] unless `vertex` is a vertex of the graph.
+    #[track_caller]
+    pub(crate) fn check_vertex(&self, vertex: VertexId) -> Result<VertexId> {
+        if vertex.index() < self.num_vertices() {
+            Ok(vertex)
+        } else {
+            Err(IgraphError::invalid_vertex(format!(
+                "vertex {vertex} does not exist in a graph with {} vertices",
+                self.num_vertices()
+            )))
+        }
+    }
+
+    /// Fails with [IgraphError::InvalidValue] unless there is one weight per edge.
+    #[track_caller]
+    pub(crate) fn check_weights<'w>(&self, weights: Option<&'w [f64]>) -> Result<Weights<'w>> {
+        match weights {
+            Some(weights) if weights.len() != self.num_edges() => {
+                Err(IgraphError::invalid_value(format!(
+                    "{} weights given for a graph with {} edges",
+                    weights.len(),
+                    self.num_edges()
+                )))
+            }
+            _ => Ok(Weights(weights.map(VectorView::new))),
+        }
+    }
+
+    /// Fails with [IgraphError::InvalidEdgeId] unless `edge` is an edge of the graph.
+    #[track_caller]
+    pub(crate) fn check_edge(&self, edge: EdgeId) -> Result<EdgeId> {
+        if edge.index() < self.num_edges() {
+            Ok(edge)
+        } else {
+            Err(IgraphError::invalid_edge(format!(
+                "edge {edge} does not exist in a graph with {} edges",
+                self.num_edges()
+            )))
+        }
+    }
+
+    /// Removes the selected vertices and their incident edges; the remaining vertices
+    /// are renumbered keeping their relative order.
+    pub fn delete_vertices(&mut self, vertices: VertexSelector<'_>) -> Result<()> {
+        let vertices = vertices.to_checked_raw(self)?;
+        call(|| unsafe { igraph_delete_vertices(self.as_mut_ptr(), vertices.as_raw()) })
+    }
+
+    /// Removes the selected edges; the remaining edges are renumbered keeping their relative order.
+    pub fn delete_edges(&mut self, edges: EdgeSelector<'_>) -> Result<()> {
+        let edges = edges.to_checked_raw(self)?;
+        call(|| unsafe { igraph_delete_edges(self.as_mut_ptr(), edges.as_raw()) })
+    }
+
+    /// The subgraph made of the selected vertices and the edges between them.
+    pub fn induced_subgraph(&self, vertices: VertexSelector<'_>) -> Result<Self> {
+        let vertices = vertices.to_checked_raw(self)?;
+        Self::init_with(|graph| unsafe {
+            igraph_induced_subgraph(
+                self.as_ptr(),
+                graph,
+                vertices.as_raw(),
+                igraph_subgraph_implementation_t_IGRAPH_SUBGRAPH_AUTO,
+            )
+        })
+    }
+
+    /// A copy of the graph where vertex `v` becomes vertex `permutation[v]`.
+    pub fn permute_vertices(&self, permutation: &[VertexId]) -> Result<Self> {
+        if permutation.len() != self.num_vertices() {
+            return Err(IgraphError::invalid_value(format!(
+                "the permutation has {} entries for {} vertices",
+                permutation.len(),
+                self.num_vertices()
+            )));
+        }
+        for &v in permutation {
+            self.check_vertex(v)?;
+        }
+        let permutation = VectorView::new(VertexId::as_raw_slice(permutation));
+        Self::init_with(|graph| unsafe {
+            igraph_permute_vertices(self.as_ptr(), graph, permutation.as_ptr())
+        })
+    }
+}
+
+/// Panics if igraph cannot allocate the copy; use [Graph::try_clone] to handle the failure.
+impl<D: Directedness> Clone for Graph<D> {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("igraph failed to copy the graph")
+    }
+}
+
+impl<D: Directedness> fmt::Debug for Graph<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Graph")
+            .field("directed", &self.is_directed())
+            .field("num_vertices", &self.num_vertices())
+            .field("num_edges", &self.num_edges())
+            .finish()
+    }
+}
+
+macro_rules! impl_typed_conversions {
+    ($marker:ident, $directed:literal) => {
+        /// Fails, giving the graph back, unless its directedness matches.
+        impl TryFrom<Graph> for Graph<$marker> {
+            type Error = Graph;
+
+            fn try_from(graph: Graph) -> std::result::Result<Self, Graph> {
+                if graph.is_directed() == $directed {
+                    Ok(graph.retype())
+                } else {
+                    Err(graph)
+                }
+            }
+        }
+
+        impl From<Graph<$marker>> for Graph {
+            fn from(graph: Graph<$marker>) -> Self {
+                graph.into_dynamic()
+            }
+        }
+    };
+}
+
+impl_typed_conversions!(Directed, true);
+impl_typed_conversions!(Undirected, false);
@@ -0,0 +1,738 @@
+//! Preferential-attachment and growth models of random graphs.
+//!
+//! The models with many parameters are configured with builder structs: `new` takes the
+//! required parameters and starts from directed linear preferential attachment without
+//! aging, the other parameters are set by chaining methods, and `generate` builds the
+//! graph. Their graphs are directed, with edges pointing from the citing vertex to the
+//! cited ones, unless `directed(false)` is set.
+//!
+//! The models draw from the default generator of the calling thread; run them inside
+//! [rng::with_default_rng](crate::rng::with_default_rng) with a seeded
+//! [Rng](crate::Rng) for reproducible graphs.
+
+use igraph_sys::{
+    igraph_barabasi_aging_game, igraph_barabasi_algorithm_t,
+    igraph_barabasi_algorithm_t_IGRAPH_BARABASI_BAG,
+    igraph_barabasi_algorithm_t_IGRAPH_BARABASI_PSUMTREE,
+    igraph_barabasi_algorithm_t_IGRAPH_BARABASI_PSUMTREE_MULTIPLE, igraph_barabasi_game,
+    igraph_cited_type_game, igraph_citing_cited_type_game, igraph_forest_fire_game,
+    igraph_growing_random_game, igraph_lastcit_game, igraph_recent_degree_aging_game,
+    igraph_recent_degree_game, igraph_vector_int_t,
+};
+
+use crate::{Graph, IgraphError, Matrix, Result, generators::sizes, vector::VectorView};
+
+/// How [BarabasiGame] samples the targets of new edges, mirroring
+/// `igraph_barabasi_algorithm_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BarabasiAlgorithm {
+    /// Draws targets from a bag holding each vertex once per unit of in-degree, plus
+    /// once; only supports a power of one and a zero appeal of one, and may create
+    /// multi-edges.
+    Bag,
+    /// Draws targets from a partial prefix-sum tree, without creating multi-edges.
+    Psumtree,
+    /// Like [BarabasiAlgorithm::Psumtree], but allows multi-edges.
+    PsumtreeMultiple,
+}
+
+impl BarabasiAlgorithm {
+    pub(crate) fn raw(self) -> igraph_barabasi_algorithm_t {
+        match self {
+            Self::Bag => igraph_barabasi_algorithm_t_IGRAPH_BARABASI_BAG,
+            Self::Psumtree => igraph_barabasi_algorithm_t_IGRAPH_BARABASI_PSUMTREE,
+            Self::PsumtreeMultiple => igraph_barabasi_algorithm_t_IGRAPH_BARABASI_PSUMTREE_MULTIPLE,
+        }
+    }
+}
+
+/// How many edges each new vertex adds: the same number for all, or one number per vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgesPerStep<'a> {
+    Constant(usize),
+    Sequence(&'a [usize]),
+}
+
+impl EdgesPerStep<'_> {
+    /// The `m` and `outseq` arguments igraph takes, the latter viewing `storage`.
+    fn raw(self, storage: &mut Vec<i64>) -> (i64, Option<VectorView<'_, i64>>) {
+        match self {
+            Self::Constant(m) => (m as i64, None),
+            Self::Sequence(sequence) => {
+                *storage = sizes(sequence);
+                (0, Some(VectorView::new(storage)))
+            }
+        }
+    }
+}
+
+fn sequence_ptr(sequence: &Option<VectorView<'_, i64>>) -> *const igraph_vector_int_t {
+    sequence
+        .as_ref()
+        .map_or(std::ptr::null(), VectorView::as_ptr)
+}
+
+/// The Barabási–Albert model and its generalizations, binding
+/// [igraph_barabasi_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_barabasi_game).
+///
+/// Vertices are added one at a time, each citing existing vertices with probability
+/// proportional to `d^power + zero_appeal`, where `d` is their in-degree, or their total
+/// degree with [out_pref](BarabasiGame::out_pref) or in undirected graphs.
+#[derive(Debug, Clone, Copy)]
+pub struct BarabasiGame<'a> {
+    num_vertices: usize,
+    power: f64,
+    edges_per_step: EdgesPerStep<'a>,
+    out_pref: bool,
+    zero_appeal: f64,
+    directed: bool,
+    algorithm: BarabasiAlgorithm,
+    start_from: Option<&'a Graph>,
+}
+
+impl<'a> BarabasiGame<'a> {
+    /// A directed linear preferential-attachment model on `num_vertices` vertices, each
+    /// adding one edge.
+    pub fn new(num_vertices: usize) -> Self {
+        Self {
+            num_vertices,
+            power: 1.0,
+            edges_per_step: EdgesPerStep::Constant(1),
+            out_pref: false,
+            zero_appeal: 1.0,
+            directed: true,
+            algorithm: BarabasiAlgorithm::Psumtree,
+            start_from: None,
+        }
+    }
+
+    /// The exponent of the degree in the attachment probability.
+    pub fn power(mut self, power: f64) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// The number of edges every new vertex adds.
+    pub fn edges_per_step(mut self, edges: usize) -> Self {
+        self.edges_per_step = EdgesPerStep::Constant(edges);
+        self
+    }
+
+    /// The number of edges each vertex adds. Without [start_from](Self::start_from) there
+    /// is one entry per vertex and the first is ignored; with it, one entry per vertex
+    /// added to the seed graph.
+    pub fn out_sequence(mut self, edges: &'a [usize]) -> Self {
+        self.edges_per_step = EdgesPerStep::Sequence(edges);
+        self
+    }
+
+    /// Whether out-edges also count towards the degree that attracts new edges.
+    pub fn out_pref(mut self, out_pref: bool) -> Self {
+        self.out_pref = out_pref;
+        self
+    }
+
+    /// The attractiveness of vertices of degree zero.
+    pub fn zero_appeal(mut self, zero_appeal: f64) -> Self {
+        self.zero_appeal = zero_appeal;
+        self
+    }
+
+    /// Without direction, the total degree of a vertex attracts new edges, as with
+    /// [out_pref](Self::out_pref).
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    /// How the vertices to attach to are drawn.
+    pub fn algorithm(mut self, algorithm: BarabasiAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Grows `graph` instead of starting from a single vertex; its vertices count towards
+    /// the total number of vertices.
+    pub fn start_from(mut self, graph: &'a Graph) -> Self {
+        self.start_from = Some(graph);
+        self
+    }
+
+    /// Grows the graph from [start_from](Self::start_from) if set; fails if the
+    /// [out_sequence](Self::out_sequence) does not have an entry per added vertex.
+    pub fn generate(&self) -> Result<Graph> {
+        if let EdgesPerStep::Sequence(edges) = self.edges_per_step {
+            let expected = self.start_from.map_or(self.num_vertices, |seed| {
+                self.num_vertices.saturating_sub(seed.num_vertices())
+            });
+            if edges.len() != expected {
+                return Err(IgraphError::invalid_value(format!(
+                    "the out-sequence has {} entries but {expected} vertices are added",
+                    edges.len()
+                )));
+            }
+        }
+        let mut storage = Vec::new();
+        let (m, sequence) = self.edges_per_step.raw(&mut storage);
+        Graph::init_with(|graph| unsafe {
+            igraph_barabasi_game(
+                graph,
+                self.num_vertices as i64,
+                self.power,
+                m,
+                sequence_ptr(&sequence),
+                self.out_pref,
+                self.zero_appeal,
+                self.directed,
+                self.algorithm.raw(),
+                self.start_from.map_or(std::ptr::null(), Graph::as_ptr),
+            )
+        })
+    }
+}
+
+/// Preferential attachment with aging, binding
+/// [igraph_barabasi_aging_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_barabasi_aging_game).
+///
+/// Vertices are cited with probability proportional to
+/// `(deg_coef * d^pa_exp + zero_deg_appeal) * (age_coef * a^aging_exp + zero_age_appeal)`,
+/// where `d` is their in-degree and `a` their age, counted in bins of `aging_bin` steps.
+#[derive(Debug, Clone, Copy)]
+pub struct BarabasiAgingGame<'a> {
+    num_vertices: usize,
+    edges_per_step: EdgesPerStep<'a>,
+    out_pref: bool,
+    pa_exp: f64,
+    aging_exp: f64,
+    aging_bin: usize,
+    zero_deg_appeal: f64,
+    zero_age_appeal: f64,
+    deg_coef: f64,
+    age_coef: f64,
+    directed: bool,
+}
+
+impl<'a> BarabasiAgingGame<'a> {
+    /// A directed model on `num_vertices` vertices, each adding one edge, with linear
+    /// preferential attachment and no aging.
+    pub fn new(num_vertices: usize) -> Self {
+        Self {
+            num_vertices,
+            edges_per_step: EdgesPerStep::Constant(1),
+            out_pref: false,
+            pa_exp: 1.0,
+            aging_exp: 0.0,
+            aging_bin: 1,
+            zero_deg_appeal: 1.0,
+            zero_age_appeal: 0.0,
+            deg_coef: 1.0,
+            age_coef: 1.0,
+            directed: true,
+        }
+    }
+
+    /// The number of edges every new vertex adds.
+    pub fn edges_per_step(mut self, edges: usize) -> Self {
+        self.edges_per_step = EdgesPerStep::Constant(edges);
+        self
+    }
+
+    /// The number of edges each vertex adds, one entry per vertex; the first is ignored.
+    pub fn out_sequence(mut self, edges: &'a [usize]) -> Self {
+        self.edges_per_step = EdgesPerStep::Sequence(edges);
+        self
+    }
+
+    /// Whether out-edges also count towards the degree that attracts new edges.
+    pub fn out_pref(mut self, out_pref: bool) -> Self {
+        self.out_pref = out_pref;
+        self
+    }
+
+    /// The exponent of the degree.
+    pub fn pa_exp(mut self, pa_exp: f64) -> Self {
+        self.pa_exp = pa_exp;
+        self
+    }
+
+    /// The exponent of the age, usually negative so that older vertices attract less.
+    pub fn aging_exp(mut self, aging_exp: f64) -> Self {
+        self.aging_exp = aging_exp;
+        self
+    }
+
+    /// The number of steps after which a vertex ages by one.
+    pub fn aging_bin(mut self, aging_bin: usize) -> Self {
+        self.aging_bin = aging_bin;
+        self
+    }
+
+    /// The attractiveness of vertices of degree zero.
+    pub fn zero_deg_appeal(mut self, zero_deg_appeal: f64) -> Self {
+        self.zero_deg_appeal = zero_deg_appeal;
+        self
+    }
+
+    /// The attractiveness of vertices of age zero.
+    pub fn zero_age_appeal(mut self, zero_age_appeal: f64) -> Self {
+        self.zero_age_appeal = zero_age_appeal;
+        self
+    }
+
+    /// The coefficient of the degree term.
+    pub fn deg_coef(mut self, deg_coef: f64) -> Self {
+        self.deg_coef = deg_coef;
+        self
+    }
+
+    /// The coefficient of the age term.
+    pub fn age_coef(mut self, age_coef: f64) -> Self {
+        self.age_coef = age_coef;
+        self
+    }
+
+    /// Without direction, `d` counts all the edges of a vertex; its age is unaffected.
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    pub fn generate(&self) -> Result<Graph> {
+        let mut storage = Vec::new();
+        let (m, sequence) = self.edges_per_step.raw(&mut storage);
+        Graph::init_with(|graph| unsafe {
+            igraph_barabasi_aging_game(
+                graph,
+                self.num_vertices as i64,
+                m,
+                sequence_ptr(&sequence),
+                self.out_pref,
+                self.pa_exp,
+                self.aging_exp,
+                self.aging_bin as i64,
+                self.zero_deg_appeal,
+                self.zero_age_appeal,
+                self.deg_coef,
+                self.age_coef,
+                self.directed,
+            )
+        })
+    }
+}
+
+/// Preferential attachment to the vertices that gained edges recently, binding
+/// [igraph_recent_degree_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_recent_degree_game).
+///
+/// Vertices are cited with probability proportional to `d^power + zero_appeal`, where
+/// `d` is the number of edges they gained in the last `window` steps.
+#[derive(Debug, Clone, Copy)]
+pub struct RecentDegreeGame<'a> {
+    num_vertices: usize,
+    power: f64,
+    window: usize,
+    edges_per_step: EdgesPerStep<'a>,
+    out_pref: bool,
+    zero_appeal: f64,
+    directed: bool,
+}
+
+impl<'a> RecentDegreeGame<'a> {
+    /// A directed model on `num_vertices` vertices, each adding one edge, counting the
+    /// edges gained in the last `window` steps linearly.
+    pub fn new(num_vertices: usize, window: usize) -> Self {
+        Self {
+            num_vertices,
+            power: 1.0,
+            window,
+            edges_per_step: EdgesPerStep::Constant(1),
+            out_pref: false,
+            zero_appeal: 1.0,
+            directed: true,
+        }
+    }
+
+    /// The exponent of the recent degree.
+    pub fn power(mut self, power: f64) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// The number of edges every new vertex adds.
+    pub fn edges_per_step(mut self, edges: usize) -> Self {
+        self.edges_per_step = EdgesPerStep::Constant(edges);
+        self
+    }
+
+    /// The number of edges each vertex adds, one entry per vertex; the first is ignored.
+    pub fn out_sequence(mut self, edges: &'a [usize]) -> Self {
+        self.edges_per_step = EdgesPerStep::Sequence(edges);
+        self
+    }
+
+    /// Whether recent out-edges also count towards the degree that attracts new edges.
+    pub fn out_pref(mut self, out_pref: bool) -> Self {
+        self.out_pref = out_pref;
+        self
+    }
+
+    /// The attractiveness of vertices that gained no edges recently.
+    pub fn zero_appeal(mut self, zero_appeal: f64) -> Self {
+        self.zero_appeal = zero_appeal;
+        self
+    }
+
+    /// Without direction, a vertex gains an edge at either end, citing or cited.
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    pub fn generate(&self) -> Result<Graph> {
+        let mut storage = Vec::new();
+        let (m, sequence) = self.edges_per_step.raw(&mut storage);
+        Graph::init_with(|graph| unsafe {
+            igraph_recent_degree_game(
+                graph,
+                self.num_vertices as i64,
+                self.power,
+                self.window as i64,
+                m,
+                sequence_ptr(&sequence),
+                self.out_pref,
+                self.zero_appeal,
+                self.directed,
+            )
+        })
+    }
+}
+
+/// Recent-degree preferential attachment with aging, binding
+/// [igraph_recent_degree_aging_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_recent_degree_aging_game).
+///
+/// Vertices are cited with probability proportional to `(d^pa_exp + zero_appeal) * a^aging_exp`,
+/// where `d` is the number of edges they gained in the last `window` steps and `a` their
+/// age, counted in bins of `aging_bin` steps.
+#[derive(Debug, Clone, Copy)]
+pub struct RecentDegreeAgingGame<'a> {
+    num_vertices: usize,
+    edges_per_step: EdgesPerStep<'a>,
+    out_pref: bool,
+    pa_exp: f64,
+    aging_exp: f64,
+    aging_bin: usize,
+    window: usize,
+    zero_appeal: f64,
+    directed: bool,
+}
+
+impl<'a> RecentDegreeAgingGame<'a> {
+    /// A directed model on `num_vertices` vertices, each adding one edge, counting the
+    /// edges gained in the last `window` steps linearly, without aging.
+    pub fn new(num_vertices: usize, window: usize) -> Self {
+        Self {
+            num_vertices,
+            edges_per_step: EdgesPerStep::Constant(1),
+            out_pref: false,
+            pa_exp: 1.0,
+            aging_exp: 0.0,
+            aging_bin: 1,
+            window,
+            zero_appeal: 1.0,
+            directed: true,
+        }
+    }
+
+    /// The number of edges every new vertex adds.
+    pub fn edges_per_step(mut self, edges: usize) -> Self {
+        self.edges_per_step = EdgesPerStep::Constant(edges);
+        self
+    }
+
+    /// The number of edges each vertex adds, one entry per vertex; the first is ignored.
+    pub fn out_sequence(mut self, edges: &'a [usize]) -> Self {
+        self.edges_per_step = EdgesPerStep::Sequence(edges);
+        self
+    }
+
+    /// Whether recent out-edges also count towards the degree that attracts new edges.
+    pub fn out_pref(mut self, out_pref: bool) -> Self {
+        self.out_pref = out_pref;
+        self
+    }
+
+    /// The exponent of the recent degree.
+    pub fn pa_exp(mut self, pa_exp: f64) -> Self {
+        self.pa_exp = pa_exp;
+        self
+    }
+
+    /// The exponent of the age, usually negative so that older vertices attract less.
+    pub fn aging_exp(mut self, aging_exp: f64) -> Self {
+        self.aging_exp = aging_exp;
+        self
+    }
+
+    /// The number of steps after which a vertex ages by one.
+    pub fn aging_bin(mut self, aging_bin: usize) -> Self {
+        self.aging_bin = aging_bin;
+        self
+    }
+
+    /// The attractiveness of vertices that gained no edges recently.
+    pub fn zero_appeal(mut self, zero_appeal: f64) -> Self {
+        self.zero_appeal = zero_appeal;
+        self
+    }
+
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    pub fn generate(&self) -> Result<Graph> {
+        let mut storage = Vec::new();
+        let (m, sequence) = self.edges_per_step.raw(&mut storage);
+        Graph::init_with(|graph| unsafe {
+            igraph_recent_degree_aging_game(
+                graph,
+                self.num_vertices as i64,
+                m,
+                sequence_ptr(&sequence),
+                self.out_pref,
+                self.pa_exp,
+                self.aging_exp,
+                self.aging_bin as i64,
+                self.window as i64,
+                self.zero_appeal,
+                self.directed,
+            )
+        })
+    }
+}
+
+impl Graph {
+    /// A graph grown by adding one vertex and `edges_per_step` uniformly random edges at
+    /// every step, binding
+    /// [igraph_growing_random_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_growing_random_game);
+    /// with `citation`, the new edges all start from the new vertex.
+    pub fn growing_random_game(
+        num_vertices: usize,
+        edges_per_step: usize,
+        directed: bool,
+        citation: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_growing_random_game(
+                graph,
+                num_vertices as i64,
+                edges_per_step as i64,
+                directed,
+                citation,
+            )
+        })
+    }
+
+    /// The forest fire model, binding
+    /// [igraph_forest_fire_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_forest_fire_game):
+    /// every new vertex cites `ambassadors` random vertices, then recursively the
+    /// neighbors of the cited ones, following out-edges with probability `fw_prob` and
+    /// in-edges with probability `fw_prob * bw_factor`.
+    pub fn forest_fire_game(
+        num_vertices: usize,
+        fw_prob: f64,
+        bw_factor: f64,
+        ambassadors: usize,
+        directed: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_forest_fire_game(
+                graph,
+                num_vertices as i64,
+                fw_prob,
+                bw_factor,
+                ambassadors as i64,
+                directed,
+            )
+        })
+    }
+
+    /// A citation graph in which vertices are cited with a probability depending on how
+    /// many steps ago they were last cited, binding
+    /// [igraph_lastcit_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_lastcit_game).
+    ///
+    /// Time since the last citation is counted in `age_bins` bins; `preference` has one
+    /// entry per bin plus a last one for vertices never cited.
+    pub fn lastcit_game(
+        num_vertices: usize,
+        edges_per_step: usize,
+        age_bins: usize,
+        preference: &[f64],
+        directed: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_lastcit_game(
+                graph,
+                num_vertices as i64,
+                edges_per_step as i64,
+                age_bins as i64,
+                VectorView::new(preference).as_ptr(),
+                directed,
+            )
+        })
+    }
+
+    /// A citation graph in which vertices are cited with a probability depending on their
+    /// type, binding
+    /// [igraph_cited_type_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_cited_type_game).
+    ///
+    /// `types` gives the type of each vertex and `preference` the attractiveness of each type.
+    pub fn cited_type_game(
+        num_vertices: usize,
+        types: &[i64],
+        preference: &[f64],
+        edges_per_step: usize,
+        directed: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_cited_type_game(
+                graph,
+                num_vertices as i64,
+                VectorView::new(types).as_ptr(),
+                VectorView::new(preference).as_ptr(),
+                edges_per_step as i64,
+                directed,
+            )
+        })
+    }
+
+    /// A citation graph in which vertices are cited with a probability depending on both
+    /// their type and the type of the citing vertex, binding
+    /// [igraph_citing_cited_type_game](https://igraph.org/c/html/latest/igraph-Games.html#igraph_citing_cited_type_game).
+    ///
+    /// `types` gives the type of each vertex; `preference[(citing, cited)]` is the
+    /// attractiveness of the cited type for the citing one.
+    pub fn citing_cited_type_game(
+        num_vertices: usize,
+        types: &[i64],
+        preference: &Matrix<f64>,
+        edges_per_step: usize,
+        directed: bool,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_citing_cited_type_game(
+                graph,
+                num_vertices as i64,
+                VectorView::new(types).as_ptr(),
+                preference.as_ptr(),
+                edges_per_step as i64,
+                directed,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Loops, NeighborMode, Rng, RngType, VertexSelector, rng::with_default_rng,
+        sparse::AdjacencyPart,
+    };
+
+    fn adjacency(graph: &Graph) -> Vec<Vec<f64>> {
+        let adjacency = graph
+            .get_adjacency_sparse(AdjacencyPart::Both, None, Loops::Twice)
+            .unwrap();
+        adjacency.to_dense().unwrap().to_rows()
+    }
+
+    #[test]
+    fn test_barabasi_game() {
+        let graph = BarabasiGame::new(100)
+            .edges_per_step(2)
+            .directed(false)
+            .algorithm(BarabasiAlgorithm::Bag)
+            .generate()
+            .unwrap();
+        assert_eq!(graph.num_vertices(), 100);
+        assert_eq!(graph.num_edges(), 99 * 2);
+        assert!(!graph.is_directed());
+
+        let mut first = Rng::seeded(RngType::Pcg32, 7).unwrap();
+        let mut second = Rng::seeded(RngType::Pcg32, 7).unwrap();
+        let game = BarabasiGame::new(50)
+            .power(1.5)
+            .algorithm(BarabasiAlgorithm::PsumtreeMultiple);
+        let first = with_default_rng(&mut first, || game.generate()).unwrap();
+        let second = with_default_rng(&mut second, || game.generate()).unwrap();
+        assert_eq!(adjacency(&first), adjacency(&second));
+
+        let seed = Graph::full(5, false, false).unwrap();
+        let out = [3; 5];
+        let grown = BarabasiGame::new(10)
+            .directed(false)
+            .out_sequence(&out)
+            .start_from(&seed)
+            .generate()
+            .unwrap();
+        assert_eq!(grown.num_edges(), 10 + 5 * 3);
+        assert!(matches!(
+            BarabasiGame::new(10)
+                .out_sequence(&[3; 10])
+                .start_from(&seed)
+                .generate(),
+            Err(IgraphError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_aging_and_recent_degree_games() {
+        let aging = BarabasiAgingGame::new(60)
+            .edges_per_step(2)
+            .aging_exp(-1.0)
+            .aging_bin(5)
+            .generate()
+            .unwrap();
+        assert_eq!(aging.num_vertices(), 60);
+
+        let recent = RecentDegreeGame::new(60, 10).power(2.0).generate().unwrap();
+        assert_eq!(recent.num_edges(), 59);
+
+        let recent_aging = RecentDegreeAgingGame::new(60, 10)
+            .aging_exp(-0.5)
+            .aging_bin(3)
+            .directed(false)
+            .generate()
+            .unwrap();
+        assert_eq!(recent_aging.num_edges(), 59);
+    }
+
+    #[test]
+    fn test_growth_and_citation_games() {
+        let growing = Graph::growing_random_game(30, 2, true, true).unwrap();
+        assert_eq!(growing.num_edges(), 29 * 2);
+
+        // Every vertex but the first cites at least its ambassador.
+        let fire = Graph::forest_fire_game(50, 0.3, 0.5, 1, true).unwrap();
+        let degrees = fire
+            .strength(&VertexSelector::All, NeighborMode::Out, Loops::Twice, None)
+            .unwrap();
+        assert_eq!(degrees.len(), 50);
+        assert!(degrees[1..].iter().all(|&d| d >= 1.0));
+
+        let lastcit = Graph::lastcit_game(40, 1, 4, &[4.0, 3.0, 2.0, 1.0, 0.5], true).unwrap();
+        assert_eq!(lastcit.num_edges(), 39);
+
+        let types: Vec<i64> = (0..40).map(|v| v % 2).collect();
+        let cited = Graph::cited_type_game(40, &types, &[1.0, 5.0], 1, true).unwrap();
+        assert_eq!(cited.num_vertices(), 40);
+        assert_eq!(cited.num_edges(), 39);
+
+        let preference = Matrix::from_rows(&[vec![1.0, 0.1], vec![0.1, 1.0]]).unwrap();
+        let citing = Graph::citing_cited_type_game(40, &types, &preference, 1, true).unwrap();
+        let degrees = citing
+            .strength(&VertexSelector::All, NeighborMode::Out, Loops::Twice, None)
+            .unwrap();
+        assert!(degrees[1..].iter().all(|&d| d == 1.0));
+    }
+}
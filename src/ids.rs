@@ -0,0 +1,137 @@
+//! Typed vertex and edge ids.
+//!
+//! igraph numbers the vertices and the edges of a graph with consecutive `igraph_int_t`s
+//! starting from zero. [VertexId] and [EdgeId] wrap them so that one cannot be passed
+//! for the other. Converting an `i64` or a `usize` rejects negative values and values
+//! beyond the range of `igraph_int_t`; methods taking ids then check them against
+//! [Graph::num_vertices](crate::Graph::num_vertices) or
+//! [Graph::num_edges](crate::Graph::num_edges) before calling igraph, failing with
+//! [IgraphError::InvalidVertexId] or [IgraphError::InvalidEdgeId].
+//!
+//! Both convert infallibly from `u32`, so integer literals can be used where methods take
+//! `impl Into<VertexId>`.
+
+use std::{fmt, slice};
+
+use crate::IgraphError;
+
+macro_rules! define_id {
+    ($(#[$doc:meta])* $name:ident, $what:literal, $invalid:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name(i64);
+
+        impl $name {
+            /// Wraps an id returned by igraph, which is never negative.
+            pub(crate) const fn from_raw(id: i64) -> Self {
+                Self(id)
+            }
+
+            /// The id as an `igraph_int_t`.
+            pub const fn get(self) -> i64 {
+                self.0
+            }
+
+            /// The id as an index into per-element data.
+            pub const fn index(self) -> usize {
+                self.0 as usize
+            }
+
+            /// The ids as the `igraph_int_t`s igraph takes.
+            pub(crate) fn as_raw_slice(ids: &[Self]) -> &[i64] {
+                // SAFETY: the id is a `repr(transparent)` wrapper of an `i64`.
+                unsafe { slice::from_raw_parts(ids.as_ptr().cast(), ids.len()) }
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(id: u32) -> Self {
+                Self(id.into())
+            }
+        }
+
+        impl TryFrom<i64> for $name {
+            type Error = IgraphError;
+
+            #[track_caller]
+            fn try_from(id: i64) -> Result<Self, IgraphError> {
+                if id < 0 {
+                    return Err(IgraphError::$invalid(format!(
+                        concat!($what, " id {} is negative"),
+                        id
+                    )));
+                }
+                Ok(Self(id))
+            }
+        }
+
+        impl TryFrom<usize> for $name {
+            type Error = IgraphError;
+
+            #[track_caller]
+            fn try_from(id: usize) -> Result<Self, IgraphError> {
+                match i64::try_from(id) {
+                    Ok(id) => Ok(Self(id)),
+                    Err(_) => Err(IgraphError::$invalid(format!(
+                        concat!($what, " id {} does not fit an igraph_int_t"),
+                        id
+                    ))),
+                }
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(id: $name) -> i64 {
+                id.0
+            }
+        }
+
+        impl From<$name> for usize {
+            fn from(id: $name) -> usize {
+                id.index()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+define_id!(
+    /// The id of a vertex, between zero and [Graph::num_vertices](crate::Graph::num_vertices) excluded.
+    VertexId,
+    "vertex",
+    invalid_vertex
+);
+
+define_id!(
+    /// The id of an edge, between zero and [Graph::num_edges](crate::Graph::num_edges) excluded.
+    EdgeId,
+    "edge",
+    invalid_edge
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_conversions() {
+        assert_eq!(VertexId::from(7).get(), 7);
+        assert_eq!(usize::from(EdgeId::try_from(3_i64).unwrap()), 3);
+        assert!(matches!(
+            VertexId::try_from(-1_i64),
+            Err(IgraphError::InvalidVertexId(_))
+        ));
+        assert!(matches!(
+            EdgeId::try_from(usize::MAX),
+            Err(IgraphError::InvalidEdgeId(_))
+        ));
+        let ids = [VertexId::from(2), VertexId::from(5)];
+        assert_eq!(VertexId::as_raw_slice(&ids), [2, 5]);
+    }
+}
@@ -0,0 +1,153 @@
+//! Cooperative cancellation of long-running igraph calls.
+//!
+//! igraph periodically asks the interruption handler of the calling thread whether it
+//! should stop, and if so makes the running function return `IGRAPH_INTERRUPTED`, which
+//! surfaces as [IgraphError::Interrupted](crate::IgraphError::Interrupted). [run_cancellable]
+//! and [run_with_deadline] install a handler for the duration of a closure that answers
+//! from a [CancellationToken] or a deadline; they can be nested, and the call is
+//! interrupted as soon as any of the enclosing conditions is met.
+
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use igraph_sys::{igraph_interruption_handler_t, igraph_set_interruption_handler};
+
+use crate::thread::{self, LibraryGuard};
+
+/// A cancellation flag shared between the thread running igraph and the threads that
+/// may cancel it; clones refer to the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests every call running under this token to stop at its next interruption check.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A condition under which igraph calls on this thread are interrupted.
+enum Condition {
+    Cancelled(CancellationToken),
+    Deadline(Instant),
+}
+
+impl Condition {
+    fn is_met(&self) -> bool {
+        match self {
+            Self::Cancelled(token) => token.is_cancelled(),
+            Self::Deadline(deadline) => Instant::now() >= *deadline,
+        }
+    }
+}
+
+thread_local! {
+    /// The conditions of the enclosing [run_cancellable] and [run_with_deadline] calls.
+    static CONDITIONS: RefCell<Vec<Condition>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The `igraph_interruption_handler_t` installed while conditions are active.
+unsafe extern "C" fn interrupted() -> bool {
+    CONDITIONS.with(|conditions| conditions.borrow().iter().any(Condition::is_met))
+}
+
+/// Pops the condition it pushed and restores the previous handler when dropped, even
+/// while unwinding.
+struct HandlerGuard {
+    previous: igraph_interruption_handler_t,
+    // The handler and the conditions are per thread.
+    thread: PhantomData<*const ()>,
+    // Released after the previous handler is restored.
+    _library: LibraryGuard,
+}
+
+impl HandlerGuard {
+    fn push(condition: Condition) -> Self {
+        let library = thread::lock();
+        CONDITIONS.with(|conditions| conditions.borrow_mut().push(condition));
+        Self {
+            previous: unsafe { igraph_set_interruption_handler(Some(interrupted)) },
+            thread: PhantomData,
+            _library: library,
+        }
+    }
+}
+
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_set_interruption_handler(self.previous);
+        }
+        CONDITIONS.with(|conditions| conditions.borrow_mut().pop());
+    }
+}
+
+/// Runs `f`, interrupting the igraph calls it makes on this thread once `token` is cancelled.
+pub fn run_cancellable<R>(token: &CancellationToken, f: impl FnOnce() -> R) -> R {
+    let _guard = HandlerGuard::push(Condition::Cancelled(token.clone()));
+    f()
+}
+
+/// Runs `f`, interrupting the igraph calls it makes on this thread once `timeout` elapsed.
+pub fn run_with_deadline<R>(timeout: Duration, f: impl FnOnce() -> R) -> R {
+    // A deadline too far to represent is never reached, like a token nobody cancels.
+    let condition = Instant::now().checked_add(timeout).map_or(
+        Condition::Cancelled(CancellationToken::new()),
+        Condition::Deadline,
+    );
+    let _guard = HandlerGuard::push(condition);
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use igraph_sys::igraph_allow_interruption;
+
+    use super::*;
+    use crate::{Graph, IgraphError};
+
+    #[test]
+    fn test_cancelled_calls_are_interrupted() {
+        Graph::setup().unwrap();
+        let token = CancellationToken::new();
+        let mut graph = Graph::new(2000, false).unwrap();
+        let ring: Vec<(u32, u32)> = (0..2000).map(|v| (v, (v + 1) % 2000)).collect();
+        graph.add_edges_from_slice(&ring).unwrap();
+
+        assert!(!run_cancellable(&token, || unsafe {
+            igraph_allow_interruption()
+        }));
+        token.cancel();
+        let diameter = run_cancellable(&token, || graph.diameter(None));
+        assert!(matches!(diameter, Err(IgraphError::Interrupted(_))));
+        assert_eq!(graph.diameter(None).unwrap(), 1000.0);
+    }
+
+    #[test]
+    fn test_deadlines_nest() {
+        let outer = run_with_deadline(Duration::ZERO, || {
+            run_with_deadline(Duration::MAX, || unsafe { igraph_allow_interruption() })
+        });
+        assert!(outer);
+        assert!(!run_with_deadline(Duration::MAX, || unsafe {
+            igraph_allow_interruption()
+        }));
+    }
+}
@@ -0,0 +1,46 @@
+//! Reading and writing graphs in foreign file formats.
+
+use std::ffi::{CStr, CString};
+
+use igraph_sys::{FILE, fclose, fopen, igraph_read_graph_graphml, igraph_write_graph_graphml};
+
+use crate::{Graph, IgraphError, Result, error::call, graph::Directedness};
+
+/// Opens `filename` with the C library, for the igraph readers and writers.
+fn open(filename: &str, mode: &CStr) -> Result<*mut FILE> {
+    let filename = CString::new(filename)
+        .map_err(|e| IgraphError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+    let file = unsafe { fopen(filename.as_ptr(), mode.as_ptr()) };
+    if file.is_null() {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    Ok(file)
+}
+
+impl Graph {
+    /// Reads the `index`-th graph of the GraphML file `filename`; its attributes are kept
+    /// if an attribute table is enabled.
+    pub fn read_graph_graphml(filename: &str, index: usize) -> Result<Self> {
+        let file = open(filename, c"r")?;
+        let graph = Self::init_with(|graph| unsafe {
+            igraph_read_graph_graphml(graph, file, index as i64)
+        });
+        unsafe {
+            fclose(file);
+        }
+        graph
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    /// Writes the graph to `filename` in GraphML format; a file that cannot be
+    /// opened or closed is reported as [IgraphError::Io].
+    pub fn write_graph_graphml(&self, filename: &str) -> Result<()> {
+        let file = open(filename, c"w")?;
+        let written = call(|| unsafe { igraph_write_graph_graphml(self.as_ptr(), file, false) });
+        if unsafe { fclose(file) } != 0 && written.is_ok() {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        written
+    }
+}
@@ -0,0 +1,393 @@
+//! Owned wrapper over igraph's dense matrix types.
+//!
+//! [Matrix] owns an initialized `igraph_matrix_t` (for `f64`), `igraph_matrix_int_t`
+//! (for `i64`) or `igraph_matrix_bool_t` (for `bool`). Like in igraph, elements are
+//! stored in column-major order, so columns are contiguous slices while rows are
+//! strided views.
+
+use std::{
+    fmt, mem,
+    ops::{Index, IndexMut},
+};
+
+use igraph_sys::{
+    igraph_error_t, igraph_matrix_bool_destroy, igraph_matrix_bool_init,
+    igraph_matrix_bool_init_copy, igraph_matrix_bool_resize, igraph_matrix_bool_t,
+    igraph_matrix_bool_transpose, igraph_matrix_destroy, igraph_matrix_init,
+    igraph_matrix_init_copy, igraph_matrix_int_destroy, igraph_matrix_int_init,
+    igraph_matrix_int_init_copy, igraph_matrix_int_resize, igraph_matrix_int_t,
+    igraph_matrix_int_transpose, igraph_matrix_resize, igraph_matrix_t, igraph_matrix_transpose,
+};
+
+use crate::{
+    IgraphError, Result,
+    error::call,
+    thread,
+    vector::{self, Element},
+};
+
+/// An element type for which igraph provides a matrix type.
+pub trait MatrixElement: Element {
+    /// The igraph matrix type holding elements of this type.
+    type RawMatrix;
+
+    #[doc(hidden)]
+    unsafe fn init(m: *mut Self::RawMatrix, nrow: i64, ncol: i64) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn init_copy(to: *mut Self::RawMatrix, from: *const Self::RawMatrix) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn destroy(m: *mut Self::RawMatrix);
+    #[doc(hidden)]
+    unsafe fn resize(m: *mut Self::RawMatrix, nrow: i64, ncol: i64) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn transpose(m: *mut Self::RawMatrix) -> igraph_error_t;
+    #[doc(hidden)]
+    fn shape(m: &Self::RawMatrix) -> (usize, usize);
+    #[doc(hidden)]
+    fn data(m: &Self::RawMatrix) -> &Self::Raw;
+}
+
+macro_rules! impl_matrix_element {
+    ($t:ty, $raw:ty, $init:ident, $init_copy:ident, $destroy:ident, $resize:ident, $transpose:ident) => {
+        impl MatrixElement for $t {
+            type RawMatrix = $raw;
+
+            unsafe fn init(m: *mut $raw, nrow: i64, ncol: i64) -> igraph_error_t {
+                unsafe { $init(m, nrow, ncol) }
+            }
+
+            unsafe fn init_copy(to: *mut $raw, from: *const $raw) -> igraph_error_t {
+                unsafe { $init_copy(to, from) }
+            }
+
+            unsafe fn destroy(m: *mut $raw) {
+                unsafe { $destroy(m) }
+            }
+
+            unsafe fn resize(m: *mut $raw, nrow: i64, ncol: i64) -> igraph_error_t {
+                unsafe { $resize(m, nrow, ncol) }
+            }
+
+            unsafe fn transpose(m: *mut $raw) -> igraph_error_t {
+                unsafe { $transpose(m) }
+            }
+
+            fn shape(m: &$raw) -> (usize, usize) {
+                (m.nrow as usize, m.ncol as usize)
+            }
+
+            fn data(m: &$raw) -> &<$t as Element>::Raw {
+                &m.data
+            }
+        }
+    };
+}
+
+impl_matrix_element!(
+    f64,
+    igraph_matrix_t,
+    igraph_matrix_init,
+    igraph_matrix_init_copy,
+    igraph_matrix_destroy,
+    igraph_matrix_resize,
+    igraph_matrix_transpose
+);
+impl_matrix_element!(
+    i64,
+    igraph_matrix_int_t,
+    igraph_matrix_int_init,
+    igraph_matrix_int_init_copy,
+    igraph_matrix_int_destroy,
+    igraph_matrix_int_resize,
+    igraph_matrix_int_transpose
+);
+impl_matrix_element!(
+    bool,
+    igraph_matrix_bool_t,
+    igraph_matrix_bool_init,
+    igraph_matrix_bool_init_copy,
+    igraph_matrix_bool_destroy,
+    igraph_matrix_bool_resize,
+    igraph_matrix_bool_transpose
+);
+
+/// An initialized igraph matrix, destroyed when dropped.
+pub struct Matrix<T: MatrixElement> {
+    inner: T::RawMatrix,
+}
+
+impl<T: MatrixElement> Matrix<T> {
+    /// Creates an `nrow` by `ncol` matrix of zeroes (or `false`s).
+    pub fn zeros(nrow: usize, ncol: usize) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<T::RawMatrix>() };
+        call(|| unsafe { <T as MatrixElement>::init(&mut inner, nrow as i64, ncol as i64) })?;
+        Ok(Self { inner })
+    }
+
+    /// Creates an `nrow` by `ncol` matrix from its elements in column-major order.
+    pub fn from_column_major(nrow: usize, ncol: usize, data: &[T]) -> Result<Self> {
+        if data.len() != nrow * ncol {
+            return Err(IgraphError::invalid_value(format!(
+                "{} elements do not fill a {}x{} matrix",
+                data.len(),
+                nrow,
+                ncol
+            )));
+        }
+        let mut matrix = Self::zeros(nrow, ncol)?;
+        matrix.as_mut_slice().copy_from_slice(data);
+        Ok(matrix)
+    }
+
+    /// Creates a matrix from its rows, which must all have the same length.
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Self> {
+        let nrow = rows.len();
+        let ncol = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|row| row.len() != ncol) {
+            return Err(IgraphError::invalid_value(format!(
+                "row {} has {} elements, expected {}",
+                i,
+                rows[i].len(),
+                ncol
+            )));
+        }
+        let mut matrix = Self::zeros(nrow, ncol)?;
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                matrix[(i, j)] = value;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Copies the matrix with `igraph_matrix_*_init_copy`.
+    pub fn try_clone(&self) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<T::RawMatrix>() };
+        call(|| unsafe { <T as MatrixElement>::init_copy(&mut inner, &self.inner) })?;
+        Ok(Self { inner })
+    }
+
+    pub fn nrow(&self) -> usize {
+        T::shape(&self.inner).0
+    }
+
+    pub fn ncol(&self) -> usize {
+        T::shape(&self.inner).1
+    }
+
+    /// The `(nrow, ncol)` pair.
+    pub fn shape(&self) -> (usize, usize) {
+        T::shape(&self.inner)
+    }
+
+    /// All elements, in column-major order.
+    pub fn as_slice(&self) -> &[T] {
+        vector::as_slice::<T>(T::data(&self.inner))
+    }
+
+    /// All elements, in column-major order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.nrow() * self.ncol();
+        if len == 0 {
+            return &mut [];
+        }
+        let begin = T::storage(T::data(&self.inner)).0;
+        unsafe { std::slice::from_raw_parts_mut(begin, len) }
+    }
+
+    /// The element at `(row, col)`, `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        let (nrow, ncol) = self.shape();
+        (row < nrow && col < ncol).then(|| self.as_slice()[col * nrow + row])
+    }
+
+    /// The elements of column `col`, contiguous in memory.
+    pub fn column(&self, col: usize) -> &[T] {
+        let nrow = self.nrow();
+        &self.as_slice()[col * nrow..(col + 1) * nrow]
+    }
+
+    /// The elements of row `row`, strided in memory.
+    pub fn row(&self, row: usize) -> impl ExactSizeIterator<Item = T> + '_ {
+        assert!(row < self.nrow(), "row {} out of bounds", row);
+        self.as_slice()
+            .iter()
+            .skip(row)
+            .step_by(self.nrow().max(1))
+            .copied()
+    }
+
+    /// Iterates over the columns.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.ncol()).map(|col| self.column(col))
+    }
+
+    /// Iterates over the rows.
+    pub fn rows(
+        &self,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = T> + '_> + '_ {
+        (0..self.nrow()).map(|row| self.row(row))
+    }
+
+    /// Changes the shape of the matrix. The data is not re-laid out: the prefix of the
+    /// column-major elements that still fits is kept, and new elements are zeroes (or
+    /// `false`s), so elements keep their positions only when `nrow` is unchanged.
+    pub fn resize(&mut self, nrow: usize, ncol: usize) -> Result<()> {
+        let old_len = self.as_slice().len();
+        call(|| unsafe {
+            <T as MatrixElement>::resize(&mut self.inner, nrow as i64, ncol as i64)
+        })?;
+        // igraph leaves the new elements uninitialized, so they are written before the
+        // slice accessors can expose them.
+        let begin = T::storage(T::data(&self.inner)).0;
+        for i in old_len..nrow * ncol {
+            unsafe { begin.add(i).write(T::default()) };
+        }
+        Ok(())
+    }
+
+    /// Transposes the matrix in place.
+    pub fn transpose(&mut self) -> Result<()> {
+        call(|| unsafe { <T as MatrixElement>::transpose(&mut self.inner) })
+    }
+
+    /// The rows of the matrix, each as a `Vec`.
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.rows().map(Iterator::collect).collect()
+    }
+
+    pub fn as_ptr(&self) -> *const T::RawMatrix {
+        &self.inner
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T::RawMatrix {
+        &mut self.inner
+    }
+}
+
+impl<T: MatrixElement> Drop for Matrix<T> {
+    fn drop(&mut self) {
+        unsafe {
+            <T as MatrixElement>::destroy(&mut self.inner);
+        }
+    }
+}
+
+impl<T: MatrixElement> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        let (nrow, ncol) = self.shape();
+        assert!(
+            row < nrow && col < ncol,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            nrow,
+            ncol
+        );
+        &self.as_slice()[col * nrow + row]
+    }
+}
+
+impl<T: MatrixElement> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (nrow, ncol) = self.shape();
+        assert!(
+            row < nrow && col < ncol,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            nrow,
+            ncol
+        );
+        &mut self.as_mut_slice()[col * nrow + row]
+    }
+}
+
+/// Panics if igraph cannot allocate the copy; use [Matrix::try_clone] to handle the failure.
+impl<T: MatrixElement> Clone for Matrix<T> {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("igraph failed to copy the matrix")
+    }
+}
+
+impl<T: MatrixElement + PartialEq> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: MatrixElement + fmt::Debug> fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.to_rows()).finish()
+    }
+}
+
+/// One row per line, elements separated by a space, as `igraph_matrix_print` does.
+impl<T: MatrixElement + fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for (j, value) in row.enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: MatrixElement> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = IgraphError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self> {
+        Self::from_rows(&rows)
+    }
+}
+
+impl<T: MatrixElement> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(matrix: Matrix<T>) -> Self {
+        matrix.to_rows()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_layout() {
+        let mut m = Matrix::try_from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(m.shape(), (2, 3));
+        assert_eq!(m.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(m.column(1), &[2.0, 5.0]);
+        assert_eq!(m.row(1).collect::<Vec<_>>(), vec![4.0, 5.0, 6.0]);
+        assert_eq!(m.to_string(), "1 2 3\n4 5 6\n");
+
+        m.transpose().unwrap();
+        assert_eq!(m[(2, 1)], 6.0);
+        assert_eq!(
+            Vec::from(m),
+            vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+        );
+    }
+
+    #[test]
+    fn test_resize_keeps_the_column_major_prefix() {
+        let mut m = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]).unwrap();
+        m.resize(3, 2).unwrap();
+        assert_eq!(m.as_slice(), &[1, 3, 2, 4, 0, 0]);
+        assert_eq!(m.to_rows(), vec![vec![1, 4], vec![3, 0], vec![2, 0]]);
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let err = Matrix::<i64>::from_rows(&[vec![1, 2], vec![3]]).unwrap_err();
+        assert!(matches!(err, IgraphError::InvalidValue(_)));
+    }
+}
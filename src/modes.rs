@@ -0,0 +1,50 @@
+//! Mode parameters shared by many igraph functions.
+
+use igraph_sys::{
+    igraph_loops_t, igraph_loops_t_IGRAPH_LOOPS_ONCE, igraph_loops_t_IGRAPH_LOOPS_TWICE,
+    igraph_loops_t_IGRAPH_NO_LOOPS, igraph_neimode_t, igraph_neimode_t_IGRAPH_ALL,
+    igraph_neimode_t_IGRAPH_IN, igraph_neimode_t_IGRAPH_OUT,
+};
+
+/// How to interpret edge directions in directed graphs, mirroring `igraph_neimode_t`;
+/// ignored for undirected graphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeighborMode {
+    /// Follow edge directions, i.e. consider out-neighbors.
+    Out,
+    /// Follow edges in reverse, i.e. consider in-neighbors.
+    In,
+    /// Ignore edge directions.
+    All,
+}
+
+impl NeighborMode {
+    pub(crate) fn raw(self) -> igraph_neimode_t {
+        match self {
+            Self::Out => igraph_neimode_t_IGRAPH_OUT,
+            Self::In => igraph_neimode_t_IGRAPH_IN,
+            Self::All => igraph_neimode_t_IGRAPH_ALL,
+        }
+    }
+}
+
+/// How to count self-loops in undirected graphs, mirroring `igraph_loops_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Loops {
+    /// Self-loops are ignored.
+    None,
+    /// Self-loops count twice, e.g. they add two to the degree of their vertex.
+    Twice,
+    /// Self-loops count once.
+    Once,
+}
+
+impl Loops {
+    pub(crate) fn raw(self) -> igraph_loops_t {
+        match self {
+            Self::None => igraph_loops_t_IGRAPH_NO_LOOPS,
+            Self::Twice => igraph_loops_t_IGRAPH_LOOPS_TWICE,
+            Self::Once => igraph_loops_t_IGRAPH_LOOPS_ONCE,
+        }
+    }
+}
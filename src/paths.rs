@@ -0,0 +1,133 @@
+//! Shortest paths and path-based structural measures.
+
+use igraph_sys::{igraph_diameter, igraph_distances_dijkstra, igraph_get_shortest_path_dijkstra};
+
+use crate::{
+    EdgeId, Graph, Matrix, NeighborMode, Result, VertexId, VertexSelector, error::call,
+    graph::Directedness, vector::Vector,
+};
+
+impl<D: Directedness> Graph<D> {
+    /// [Calculates the diameter of a graph (longest geodesic)](https://igraph.org/c/html/0.10.2/igraph-Structural.html#igraph_diameter):
+    ///
+    /// The diameter of a graph is the length of the longest shortest path it has.
+    /// This function computes both the diameter, as well as the corresponding path.
+    /// The diameter of the null graph is considered be infinity by convention.
+    /// If the graph has no vertices, IGRAPH_NAN is returned.
+    ///
+    /// With `weights`, path lengths are the sums of the weights of their edges.
+    pub fn diameter(&self, weights: Option<&[f64]>) -> Result<f64> {
+        let weights = self.check_weights(weights)?;
+        let mut diameter = 0.0;
+        call(|| unsafe {
+            igraph_diameter(
+                self.as_ptr(),
+                weights.as_ptr(),
+                &mut diameter,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                self.is_directed(),
+                true,
+            )
+        })?;
+        Ok(diameter)
+    }
+
+    /// The lengths of the shortest paths from the vertices of `from` (rows) to those of
+    /// `to` (columns), infinite between unreachable pairs, binding
+    /// [igraph_distances_dijkstra](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_distances_dijkstra).
+    pub fn distances_dijkstra(
+        &self,
+        from: &VertexSelector<'_>,
+        to: &VertexSelector<'_>,
+        weights: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<Matrix<f64>> {
+        let weights = self.check_weights(weights)?;
+        let (from, to) = (from.to_checked_raw(self)?, to.to_checked_raw(self)?);
+        let mut distances = Matrix::zeros(0, 0)?;
+        call(|| unsafe {
+            igraph_distances_dijkstra(
+                self.as_ptr(),
+                distances.as_mut_ptr(),
+                from.as_raw(),
+                to.as_raw(),
+                weights.as_ptr(),
+                mode.raw(),
+            )
+        })?;
+        Ok(distances)
+    }
+
+    /// The vertices and edges of a shortest path from `from` to `to`, both empty if `to`
+    /// cannot be reached, binding
+    /// [igraph_get_shortest_path_dijkstra](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_get_shortest_path_dijkstra).
+    pub fn shortest_path_dijkstra(
+        &self,
+        from: impl Into<VertexId>,
+        to: impl Into<VertexId>,
+        weights: Option<&[f64]>,
+        mode: NeighborMode,
+    ) -> Result<(Vec<VertexId>, Vec<EdgeId>)> {
+        let from = self.check_vertex(from.into())?;
+        let to = self.check_vertex(to.into())?;
+        let weights = self.check_weights(weights)?;
+        let mut vertices = Vector::<i64>::new()?;
+        let mut edges = Vector::<i64>::new()?;
+        call(|| unsafe {
+            igraph_get_shortest_path_dijkstra(
+                self.as_ptr(),
+                vertices.as_mut_ptr(),
+                edges.as_mut_ptr(),
+                from.get(),
+                to.get(),
+                weights.as_ptr(),
+                mode.raw(),
+            )
+        })?;
+        Ok((
+            vertices.iter().copied().map(VertexId::from_raw).collect(),
+            edges.iter().copied().map(EdgeId::from_raw).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IgraphError;
+
+    #[test]
+    fn test_weighted_paths() {
+        let mut graph = Graph::new(4, false).unwrap();
+        graph
+            .add_edges_from_slice(&[(0, 1), (1, 2), (0, 2), (2, 3)])
+            .unwrap();
+        let weights = [1.0, 1.0, 5.0, 0.5];
+
+        assert_eq!(graph.diameter(None).unwrap(), 2.0);
+        assert_eq!(graph.diameter(Some(&weights)).unwrap(), 2.5);
+        assert!(matches!(
+            graph.diameter(Some(&weights[..3])),
+            Err(IgraphError::InvalidValue(_))
+        ));
+
+        let distances = graph
+            .distances_dijkstra(
+                &VertexSelector::Single(0.into()),
+                &VertexSelector::All,
+                Some(&weights),
+                NeighborMode::All,
+            )
+            .unwrap();
+        assert_eq!(distances.to_rows(), [vec![0.0, 1.0, 2.0, 2.5]]);
+
+        let (vertices, edges) = graph
+            .shortest_path_dijkstra(0, 2, Some(&weights), NeighborMode::All)
+            .unwrap();
+        assert_eq!(vertices, [0, 1, 2].map(VertexId::from));
+        assert_eq!(edges, [0, 1].map(EdgeId::from));
+    }
+}
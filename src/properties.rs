@@ -0,0 +1,90 @@
+//! Basic structural properties of graphs.
+
+use igraph_sys::{
+    igraph_mean_degree, igraph_minimum_spanning_tree, igraph_mst_algorithm_t_IGRAPH_MST_AUTOMATIC,
+    igraph_strength,
+};
+
+use crate::{
+    EdgeId, Graph, Loops, NeighborMode, Result, VertexSelector, error::call, graph::Directedness,
+    vector::Vector,
+};
+
+impl<D: Directedness> Graph<D> {
+    pub fn mean_degree(&self, loops: bool) -> Result<f64> {
+        let mut mean_degree = 0.0;
+        call(|| unsafe { igraph_mean_degree(self.as_ptr(), &mut mean_degree, loops) })?;
+        Ok(mean_degree)
+    }
+
+    /// The strength of the selected vertices, i.e. the sum of the weights of their edges,
+    /// or their degree when `weights` is `None`, binding
+    /// [igraph_strength](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_strength).
+    pub fn strength(
+        &self,
+        vertices: &VertexSelector<'_>,
+        mode: NeighborMode,
+        loops: Loops,
+        weights: Option<&[f64]>,
+    ) -> Result<Vec<f64>> {
+        let weights = self.check_weights(weights)?;
+        let vertices = vertices.to_checked_raw(self)?;
+        let mut strength = Vector::<f64>::new()?;
+        call(|| unsafe {
+            igraph_strength(
+                self.as_ptr(),
+                strength.as_mut_ptr(),
+                vertices.as_raw(),
+                mode.raw(),
+                loops.raw(),
+                weights.as_ptr(),
+            )
+        })?;
+        Ok(strength.into())
+    }
+
+    /// The edges of a minimum spanning forest, of minimum total weight when `weights` is
+    /// given, binding
+    /// [igraph_minimum_spanning_tree](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_minimum_spanning_tree).
+    pub fn minimum_spanning_tree(&self, weights: Option<&[f64]>) -> Result<Vec<EdgeId>> {
+        let weights = self.check_weights(weights)?;
+        let mut edges = Vector::<i64>::new()?;
+        call(|| unsafe {
+            igraph_minimum_spanning_tree(
+                self.as_ptr(),
+                edges.as_mut_ptr(),
+                weights.as_ptr(),
+                igraph_mst_algorithm_t_IGRAPH_MST_AUTOMATIC,
+            )
+        })?;
+        Ok(edges.iter().copied().map(EdgeId::from_raw).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strength_and_spanning_tree() {
+        let mut graph = Graph::new(3, false).unwrap();
+        graph
+            .add_edges_from_slice(&[(0, 1), (1, 2), (2, 0)])
+            .unwrap();
+        let weights = [3.0, 1.0, 2.0];
+
+        let strength = graph
+            .strength(
+                &VertexSelector::All,
+                NeighborMode::All,
+                Loops::Twice,
+                Some(&weights),
+            )
+            .unwrap();
+        assert_eq!(strength, [5.0, 4.0, 3.0]);
+
+        let mut tree = graph.minimum_spanning_tree(Some(&weights)).unwrap();
+        tree.sort();
+        assert_eq!(tree, [1, 2].map(EdgeId::from));
+    }
+}
@@ -0,0 +1,256 @@
+//! Progress, status and warning reports.
+//!
+//! igraph reports the progress of slow computations (e.g. betweenness or layouts), status
+//! messages and warnings through handlers of the calling thread; by default it ignores
+//! progress and status and prints warnings to stderr. The `set_*_handler` functions route
+//! them to Rust closures instead, and with the `log` or `tracing` feature
+//! `forward_to_log`/`forward_to_tracing` send them to those ecosystems under the `igraph`
+//! target: warnings at the warn level, status at info and progress at debug.
+//!
+//! Handlers may replace or reset the handlers. A panicking progress or status handler
+//! interrupts the computation that reported, which fails with
+//! [IgraphError::Interrupted](crate::IgraphError::Interrupted).
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    ffi::{CStr, c_char, c_int, c_void},
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    thread::LocalKey,
+};
+
+use igraph_sys::{
+    igraph_error_t, igraph_error_type_t_IGRAPH_INTERRUPTED, igraph_error_type_t_IGRAPH_SUCCESS,
+    igraph_set_progress_handler, igraph_set_status_handler, igraph_set_warning_handler,
+    igraph_warning_handler_print,
+};
+
+use crate::{error, thread};
+
+/// A warning reported by igraph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Warning {
+    /// What igraph warns about.
+    pub reason: String,
+    /// The C source file in which the warning was raised.
+    pub file: String,
+    /// The line of `file` at which the warning was raised.
+    pub line: i32,
+}
+
+type ProgressHandler = dyn FnMut(&str, f64);
+type StatusHandler = dyn FnMut(&str);
+type WarningHandler = dyn FnMut(&Warning);
+
+// The slots hold shared handlers, cloned out while they run, so that a handler can
+// replace or reset the handlers without finding its own slot borrowed.
+type Handler<F> = RefCell<Option<Rc<RefCell<F>>>>;
+type Slot<F> = LocalKey<Handler<F>>;
+
+thread_local! {
+    static PROGRESS: Handler<ProgressHandler> = const { RefCell::new(None) };
+    static STATUS: Handler<StatusHandler> = const { RefCell::new(None) };
+    static WARNING: Handler<WarningHandler> = const { RefCell::new(None) };
+}
+
+fn lossy(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() }
+    }
+}
+
+/// Runs `report` with the handler in `slot`, if any. A handler reporting while it is
+/// already running, e.g. because it calls igraph itself, finds itself borrowed and the
+/// nested report is dropped. Unwinding into igraph would abort the process, so a panic
+/// is caught and returned as its message.
+fn run<F: ?Sized>(
+    slot: &'static Slot<F>,
+    report: impl FnOnce(&mut F),
+) -> std::result::Result<(), String> {
+    let Some(handler) = slot.with(|slot| slot.borrow().clone()) else {
+        return Ok(());
+    };
+    let Ok(mut handler) = handler.try_borrow_mut() else {
+        return Ok(());
+    };
+    panic::catch_unwind(AssertUnwindSafe(|| report(&mut handler))).map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or("unknown panic payload", |message| message)
+            .to_string(),
+    }
+}
+
+/// What a progress or status trampoline returns: a panic in the handler interrupts the
+/// igraph computation, whose error carries the panic message.
+fn outcome(handler: &str, result: std::result::Result<(), String>) -> igraph_error_t {
+    match result {
+        Ok(()) => igraph_error_type_t_IGRAPH_SUCCESS,
+        Err(panic) => error::record(
+            igraph_error_type_t_IGRAPH_INTERRUPTED,
+            format!("the {handler} handler panicked: {panic}"),
+        ),
+    }
+}
+
+unsafe extern "C" fn report_progress(
+    message: *const c_char,
+    percent: f64,
+    _data: *mut c_void,
+) -> igraph_error_t {
+    let result = run(&PROGRESS, |handler| handler(&lossy(message), percent));
+    outcome("progress", result)
+}
+
+unsafe extern "C" fn report_status(message: *const c_char, _data: *mut c_void) -> igraph_error_t {
+    let result = run(&STATUS, |handler| handler(&lossy(message)));
+    outcome("status", result)
+}
+
+// igraph cannot be told that a warning handler failed, so its panics are only reported
+// by the panic hook.
+unsafe extern "C" fn report_warning(reason: *const c_char, file: *const c_char, line: c_int) {
+    let _ = run(&WARNING, |handler| {
+        handler(&Warning {
+            reason: lossy(reason),
+            file: lossy(file),
+            line,
+        })
+    });
+}
+
+fn set<F: ?Sized>(slot: &'static Slot<F>, handler: Option<Rc<RefCell<F>>>) {
+    slot.with(|slot| *slot.borrow_mut() = handler);
+}
+
+/// Calls `handler` with the message and the completed percentage of every progress
+/// report igraph makes on this thread.
+pub fn set_progress_handler(handler: impl FnMut(&str, f64) + 'static) {
+    set(&PROGRESS, Some(Rc::new(RefCell::new(handler))));
+    thread::serialized(|| unsafe {
+        igraph_set_progress_handler(Some(report_progress));
+    });
+}
+
+/// Calls `handler` with every status message igraph reports on this thread.
+pub fn set_status_handler(handler: impl FnMut(&str) + 'static) {
+    set(&STATUS, Some(Rc::new(RefCell::new(handler))));
+    thread::serialized(|| unsafe {
+        igraph_set_status_handler(Some(report_status));
+    });
+}
+
+/// Calls `handler` with every warning igraph reports on this thread.
+pub fn set_warning_handler(handler: impl FnMut(&Warning) + 'static) {
+    set(&WARNING, Some(Rc::new(RefCell::new(handler))));
+    thread::serialized(|| unsafe {
+        igraph_set_warning_handler(Some(report_warning));
+    });
+}
+
+/// Restores igraph's defaults on this thread: progress and status are ignored and
+/// warnings are printed to stderr.
+pub fn reset_handlers() {
+    thread::serialized(|| unsafe {
+        igraph_set_progress_handler(None);
+        igraph_set_status_handler(None);
+        igraph_set_warning_handler(Some(igraph_warning_handler_print));
+    });
+    set(&PROGRESS, None);
+    set(&STATUS, None);
+    set(&WARNING, None);
+}
+
+/// Forwards the reports igraph makes on this thread to the `log` facade.
+#[cfg(feature = "log")]
+pub fn forward_to_log() {
+    set_progress_handler(|message, percent| {
+        log::debug!(target: "igraph", "{message} {percent:.1}%");
+    });
+    set_status_handler(|message| log::info!(target: "igraph", "{}", message.trim_end()));
+    set_warning_handler(|warning| {
+        log::warn!(target: "igraph", "{} (at {}:{})", warning.reason, warning.file, warning.line);
+    });
+}
+
+/// Forwards the reports igraph makes on this thread to `tracing` events.
+#[cfg(feature = "tracing")]
+pub fn forward_to_tracing() {
+    set_progress_handler(|message, percent| {
+        tracing::debug!(target: "igraph", percent, "{message}");
+    });
+    set_status_handler(|message| tracing::info!(target: "igraph", "{}", message.trim_end()));
+    set_warning_handler(|warning| {
+        tracing::warn!(
+            target: "igraph",
+            file = %warning.file,
+            line = warning.line,
+            "{}",
+            warning.reason
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use igraph_sys::{igraph_progress, igraph_status, igraph_warning};
+
+    use super::*;
+
+    #[test]
+    fn test_reports_reach_the_closures() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let progress = Rc::clone(&seen);
+        set_progress_handler(move |message, percent| {
+            progress.borrow_mut().push(format!("{message}: {percent}"))
+        });
+        let status = Rc::clone(&seen);
+        set_status_handler(move |message| status.borrow_mut().push(message.to_string()));
+        let warnings = Rc::clone(&seen);
+        set_warning_handler(move |warning| warnings.borrow_mut().push(warning.reason.clone()));
+
+        let null = std::ptr::null_mut();
+        unsafe {
+            igraph_progress(c"Betweenness".as_ptr(), 50.0, null);
+            igraph_status(c"done".as_ptr(), null);
+            igraph_warning(c"careful".as_ptr(), c"report.rs".as_ptr(), 1);
+        }
+        reset_handlers();
+        unsafe { igraph_status(c"ignored".as_ptr(), null) };
+        assert_eq!(*seen.borrow(), ["Betweenness: 50", "done", "careful"]);
+    }
+
+    #[test]
+    fn test_handlers_may_panic_or_replace_themselves() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let replaced = Rc::clone(&seen);
+        set_status_handler(move |_| {
+            let replaced = Rc::clone(&replaced);
+            set_status_handler(move |message| replaced.borrow_mut().push(message.to_string()));
+        });
+        set_progress_handler(|_, _| panic!("no progress"));
+
+        let null = std::ptr::null_mut();
+        unsafe {
+            igraph_status(c"first".as_ptr(), null);
+            igraph_status(c"second".as_ptr(), null);
+        }
+        assert_eq!(*seen.borrow(), ["second"]);
+
+        let code = unsafe { igraph_progress(c"Betweenness".as_ptr(), 0.0, null) };
+        let err = crate::error::check(code).unwrap_err();
+        reset_handlers();
+        assert!(matches!(err, crate::IgraphError::Interrupted(_)));
+        assert!(err.to_string().contains("no progress"));
+    }
+}
@@ -0,0 +1,423 @@
+//! Random number generators.
+//!
+//! Every random generator and randomized algorithm in igraph draws from the default
+//! generator of the calling thread. [Rng] owns a separate generator built on one of
+//! igraph's [RngType]s, and [with_default_rng] makes it the default for the duration of
+//! a closure, so that results are reproducible independently of what else ran before.
+//!
+//! With the `rand` feature, `Rng::from_rand` wraps any `rand_core` generator instead, so
+//! that igraph draws from the same stream as the rest of a simulation.
+
+use std::{ffi::CStr, marker::PhantomData, mem};
+
+use igraph_sys::{
+    igraph_random_sample, igraph_rng_default, igraph_rng_destroy, igraph_rng_get_binom,
+    igraph_rng_get_bool, igraph_rng_get_exp, igraph_rng_get_gamma, igraph_rng_get_geom,
+    igraph_rng_get_integer, igraph_rng_get_normal, igraph_rng_get_pois, igraph_rng_get_unif,
+    igraph_rng_get_unif01, igraph_rng_init, igraph_rng_name, igraph_rng_sample_dirichlet,
+    igraph_rng_sample_sphere_surface, igraph_rng_sample_sphere_volume, igraph_rng_seed,
+    igraph_rng_set_default, igraph_rng_t, igraph_rng_type_t, igraph_rngtype_glibc2,
+    igraph_rngtype_mt19937, igraph_rngtype_pcg32, igraph_rngtype_pcg64,
+};
+
+use crate::{
+    Result,
+    error::call,
+    matrix::Matrix,
+    thread,
+    vector::{Vector, VectorView},
+};
+
+/// The generator types built into igraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RngType {
+    /// The Mersenne Twister MT19937.
+    Mt19937,
+    /// PCG32, the default generator of igraph on 32-bit platforms.
+    Pcg32,
+    /// PCG64, the default generator of igraph on 64-bit platforms.
+    Pcg64,
+    /// The `random()` generator of glibc 2, reimplemented by igraph.
+    Glibc2,
+}
+
+impl RngType {
+    pub(crate) fn raw(self) -> *const igraph_rng_type_t {
+        match self {
+            Self::Mt19937 => &raw const igraph_rngtype_mt19937,
+            Self::Pcg32 => &raw const igraph_rngtype_pcg32,
+            Self::Pcg64 => &raw const igraph_rngtype_pcg64,
+            Self::Glibc2 => &raw const igraph_rngtype_glibc2,
+        }
+    }
+}
+
+/// An initialized `igraph_rng_t`, released with `igraph_rng_destroy` when dropped.
+pub struct Rng {
+    inner: igraph_rng_t,
+}
+
+impl Rng {
+    /// Creates an unseeded generator; use [Rng::seeded] for reproducible draws.
+    pub fn new(kind: RngType) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<igraph_rng_t>() };
+        call(|| unsafe { igraph_rng_init(&mut inner, kind.raw()) })?;
+        Ok(Self { inner })
+    }
+
+    /// Creates a generator seeded with `seed`.
+    pub fn seeded(kind: RngType, seed: u64) -> Result<Self> {
+        let mut rng = Self::new(kind)?;
+        rng.seed(seed)?;
+        Ok(rng)
+    }
+
+    pub fn seed(&mut self, seed: u64) -> Result<()> {
+        call(|| unsafe { igraph_rng_seed(&mut self.inner, seed) })
+    }
+
+    /// The name igraph gives to the generator type, e.g. `"MT19937"`.
+    pub fn name(&self) -> &str {
+        unsafe { CStr::from_ptr(igraph_rng_name(&self.inner)) }
+            .to_str()
+            .unwrap_or_default()
+    }
+
+    /// A uniform integer between `min` and `max`, both included.
+    pub fn get_integer(&mut self, min: i64, max: i64) -> i64 {
+        thread::serialized(|| unsafe { igraph_rng_get_integer(&mut self.inner, min, max) })
+    }
+
+    /// A uniform real between `min` and `max`.
+    pub fn get_unif(&mut self, min: f64, max: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_unif(&mut self.inner, min, max) })
+    }
+
+    /// A uniform real in `[0, 1)`.
+    pub fn get_unif01(&mut self) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_unif01(&mut self.inner) })
+    }
+
+    /// A fair coin flip.
+    pub fn get_bool(&mut self) -> bool {
+        thread::serialized(|| unsafe { igraph_rng_get_bool(&mut self.inner) })
+    }
+
+    /// A normal deviate with mean `mean` and standard deviation `sd`.
+    pub fn get_normal(&mut self, mean: f64, sd: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_normal(&mut self.inner, mean, sd) })
+    }
+
+    /// The number of failures before the first success of Bernoulli trials with success
+    /// probability `p`; NaN unless `0 < p <= 1`.
+    pub fn get_geom(&mut self, p: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_geom(&mut self.inner, p) })
+    }
+
+    /// The number of successes in `n` Bernoulli trials with success probability `p`; NaN
+    /// when `n` is negative or `p` is not a probability.
+    pub fn get_binom(&mut self, n: i64, p: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_binom(&mut self.inner, n, p) })
+    }
+
+    /// An exponential deviate with rate `rate`; NaN when `rate` is negative.
+    pub fn get_exp(&mut self, rate: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_exp(&mut self.inner, rate) })
+    }
+
+    /// A gamma deviate with shape `shape` and scale `scale`; NaN unless both are positive.
+    pub fn get_gamma(&mut self, shape: f64, scale: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_gamma(&mut self.inner, shape, scale) })
+    }
+
+    /// A Poisson deviate with mean `rate`; NaN when `rate` is negative.
+    pub fn get_pois(&mut self, rate: f64) -> f64 {
+        thread::serialized(|| unsafe { igraph_rng_get_pois(&mut self.inner, rate) })
+    }
+
+    /// `n` points drawn uniformly from the surface of the `dim`-dimensional sphere of
+    /// radius `radius` centred at the origin, one per column. With `positive`, all
+    /// coordinates are non-negative.
+    pub fn sample_sphere_surface(
+        &mut self,
+        dim: usize,
+        n: usize,
+        radius: f64,
+        positive: bool,
+    ) -> Result<Matrix<f64>> {
+        let mut res = Matrix::zeros(dim, n)?;
+        call(|| unsafe {
+            igraph_rng_sample_sphere_surface(
+                &mut self.inner,
+                dim as i64,
+                n as i64,
+                radius,
+                positive,
+                res.as_mut_ptr(),
+            )
+        })?;
+        Ok(res)
+    }
+
+    /// `n` points drawn uniformly from the volume of the `dim`-dimensional ball of radius
+    /// `radius` centred at the origin, one per column. With `positive`, all coordinates are
+    /// non-negative.
+    pub fn sample_sphere_volume(
+        &mut self,
+        dim: usize,
+        n: usize,
+        radius: f64,
+        positive: bool,
+    ) -> Result<Matrix<f64>> {
+        let mut res = Matrix::zeros(dim, n)?;
+        call(|| unsafe {
+            igraph_rng_sample_sphere_volume(
+                &mut self.inner,
+                dim as i64,
+                n as i64,
+                radius,
+                positive,
+                res.as_mut_ptr(),
+            )
+        })?;
+        Ok(res)
+    }
+
+    /// `n` samples of the Dirichlet distribution with parameters `alpha`, one per column.
+    pub fn sample_dirichlet(&mut self, n: usize, alpha: &[f64]) -> Result<Matrix<f64>> {
+        let mut res = Matrix::zeros(alpha.len(), n)?;
+        call(|| unsafe {
+            igraph_rng_sample_dirichlet(
+                &mut self.inner,
+                n as i64,
+                VectorView::new(alpha).as_ptr(),
+                res.as_mut_ptr(),
+            )
+        })?;
+        Ok(res)
+    }
+
+    /// `length` distinct integers drawn uniformly between `min` and `max`, both included,
+    /// in increasing order. `igraph_random_sample` only draws from the default generator,
+    /// so this installs the generator as the default while sampling.
+    pub fn random_sample(&mut self, min: i64, max: i64, length: usize) -> Result<Vec<i64>> {
+        let mut res = Vector::<i64>::new()?;
+        with_default_rng(self, || {
+            call(|| unsafe { igraph_random_sample(res.as_mut_ptr(), min, max, length as i64) })
+        })?;
+        Ok(res.into())
+    }
+
+    pub fn as_ptr(&self) -> *const igraph_rng_t {
+        &self.inner
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut igraph_rng_t {
+        &mut self.inner
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Rng {
+    /// Wraps `rng` into an igraph generator whose raw draws are `rng.next_u64()`; igraph
+    /// derives integers, reals and the other distributions from them. Reseeding it with
+    /// [Rng::seed] replaces the generator with `R::seed_from_u64(seed)`.
+    pub fn from_rand<R: rand_core::RngCore + rand_core::SeedableRng + 'static>(
+        rng: R,
+    ) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<igraph_rng_t>() };
+        call(|| unsafe { igraph_rng_init(&mut inner, &rand::RandType::<R>::TYPE) })?;
+        unsafe {
+            *inner.state.cast::<R>() = rng;
+        }
+        inner.is_seeded = true;
+        Ok(Self { inner })
+    }
+}
+
+impl Drop for Rng {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_rng_destroy(&mut self.inner);
+        }
+    }
+}
+
+/// Seeds the default generator of the calling thread.
+pub fn seed_default(seed: u64) -> Result<()> {
+    call(|| unsafe { igraph_rng_seed(igraph_rng_default(), seed) })
+}
+
+/// Restores the previous default generator when dropped, even while unwinding.
+struct DefaultRngGuard<'a> {
+    previous: *mut igraph_rng_t,
+    // The default generator is per thread and borrows the installed `Rng`.
+    rng: PhantomData<(&'a mut Rng, *const ())>,
+    // Released after the previous default is restored.
+    _library: thread::LibraryGuard,
+}
+
+impl Drop for DefaultRngGuard<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_rng_set_default(self.previous);
+        }
+    }
+}
+
+/// Runs `f` with `rng` as the default generator of the calling thread, restoring the
+/// previous default afterwards; `rng` keeps the state `f` left it in.
+pub fn with_default_rng<R>(rng: &mut Rng, f: impl FnOnce() -> R) -> R {
+    let library = thread::lock();
+    let _guard = DefaultRngGuard {
+        previous: unsafe { igraph_rng_set_default(rng.as_mut_ptr()) },
+        rng: PhantomData,
+        _library: library,
+    };
+    f()
+}
+
+/// The `extern "C"` trampolines through which igraph drives a `rand_core` generator,
+/// boxed behind the `state` pointer of the `igraph_rng_t`.
+#[cfg(feature = "rand")]
+mod rand {
+    use std::{ffi::c_void, marker::PhantomData};
+
+    use igraph_sys::{igraph_error_t, igraph_error_type_t_IGRAPH_SUCCESS, igraph_rng_type_t};
+    use rand_core::{RngCore, SeedableRng};
+
+    pub(super) struct RandType<R>(PhantomData<R>);
+
+    impl<R: RngCore + SeedableRng + 'static> RandType<R> {
+        pub(super) const TYPE: igraph_rng_type_t = igraph_rng_type_t {
+            name: c"rand_core".as_ptr(),
+            bits: 64,
+            init: Some(init::<R>),
+            destroy: Some(destroy::<R>),
+            seed: Some(seed::<R>),
+            get: Some(get::<R>),
+            get_int: None,
+            get_real: None,
+            get_norm: None,
+            get_geom: None,
+            get_binom: None,
+            get_exp: None,
+            get_gamma: None,
+            get_pois: None,
+        };
+    }
+
+    unsafe extern "C" fn init<R: SeedableRng>(state: *mut *mut c_void) -> igraph_error_t {
+        unsafe {
+            *state = Box::into_raw(Box::new(R::seed_from_u64(0))).cast();
+        }
+        igraph_error_type_t_IGRAPH_SUCCESS
+    }
+
+    unsafe extern "C" fn destroy<R>(state: *mut c_void) {
+        drop(unsafe { Box::from_raw(state.cast::<R>()) });
+    }
+
+    unsafe extern "C" fn seed<R: SeedableRng>(state: *mut c_void, seed: u64) -> igraph_error_t {
+        unsafe {
+            *state.cast::<R>() = R::seed_from_u64(seed);
+        }
+        igraph_error_type_t_IGRAPH_SUCCESS
+    }
+
+    unsafe extern "C" fn get<R: RngCore>(state: *mut c_void) -> u64 {
+        unsafe { (*state.cast::<R>()).next_u64() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rngs_are_reproducible() {
+        let mut first = Rng::seeded(RngType::Pcg64, 7).unwrap();
+        let mut second = Rng::seeded(RngType::Pcg64, 7).unwrap();
+        let draws: Vec<i64> = (0..5).map(|_| first.get_integer(0, 100)).collect();
+        assert_eq!(
+            draws,
+            (0..5)
+                .map(|_| second.get_integer(0, 100))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Rng::new(RngType::Mt19937).unwrap().name(), "MT19937");
+    }
+
+    #[test]
+    fn test_with_default_rng_restores_the_default() {
+        let default = unsafe { igraph_rng_default() };
+        let mut rng = Rng::seeded(RngType::Mt19937, 42).unwrap();
+        let installed = with_default_rng(&mut rng, || unsafe { igraph_rng_default() });
+        assert_eq!(installed, rng.as_mut_ptr());
+        assert_eq!(unsafe { igraph_rng_default() }, default);
+    }
+
+    #[test]
+    fn test_distributions_and_samplers() {
+        let mut rng = Rng::seeded(RngType::Pcg64, 11).unwrap();
+        assert!((0.0..1.0).contains(&rng.get_unif01()));
+        assert!(rng.get_normal(0.0, 1.0).is_finite());
+        assert!(rng.get_geom(0.5) >= 0.0);
+        assert!((0.0..=10.0).contains(&rng.get_binom(10, 0.3)));
+        assert!(rng.get_exp(2.0) >= 0.0);
+        assert!(rng.get_gamma(2.0, 1.0) > 0.0);
+        assert!(rng.get_pois(4.0) >= 0.0);
+        assert!(rng.get_exp(-1.0).is_nan());
+        let flips = (0..64).filter(|_| rng.get_bool()).count();
+        assert!(0 < flips && flips < 64);
+
+        let surface = rng.sample_sphere_surface(3, 4, 2.0, false).unwrap();
+        assert_eq!(surface.shape(), (3, 4));
+        for point in surface.columns() {
+            let norm = point.iter().map(|x| x * x).sum::<f64>().sqrt();
+            assert!((norm - 2.0).abs() < 1e-9);
+        }
+        let volume = rng.sample_sphere_volume(2, 5, 1.0, true).unwrap();
+        assert!(volume.as_slice().iter().all(|x| (0.0..=1.0).contains(x)));
+        let dirichlet = rng.sample_dirichlet(3, &[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(dirichlet.shape(), (3, 3));
+        for sample in dirichlet.columns() {
+            assert!((sample.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+
+        let sample = rng.random_sample(10, 20, 5).unwrap();
+        assert_eq!(sample.len(), 5);
+        assert!(sample.windows(2).all(|w| w[0] < w[1]));
+        assert!(sample.iter().all(|v| (10..=20).contains(v)));
+        assert!(rng.random_sample(0, 3, 5).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand_generators_drive_igraph() {
+        use rand_chacha::{ChaCha8Rng, rand_core::SeedableRng};
+
+        let stream = ChaCha8Rng::seed_from_u64(3);
+        let mut rng = Rng::from_rand(stream.clone()).unwrap();
+        assert_eq!(rng.name(), "rand_core");
+        let draws: Vec<i64> = with_default_rng(&mut rng, || {
+            (0..5)
+                .map(|_| unsafe { igraph_rng_get_integer(igraph_rng_default(), 0, 1000) })
+                .collect()
+        });
+        assert!(draws.iter().all(|d| (0..=1000).contains(d)));
+        let mut replay = Rng::from_rand(stream).unwrap();
+        assert_eq!(
+            draws,
+            (0..5)
+                .map(|_| replay.get_integer(0, 1000))
+                .collect::<Vec<_>>()
+        );
+
+        rng.seed(9).unwrap();
+        let mut reseeded = Rng::from_rand(ChaCha8Rng::seed_from_u64(9)).unwrap();
+        assert_eq!(rng.get_unif(0.0, 1.0), reseeded.get_unif(0.0, 1.0));
+    }
+}
@@ -0,0 +1,545 @@
+//! Vertex and edge selectors.
+//!
+//! Many igraph functions act on a set of vertices or edges described by an
+//! `igraph_vs_t` or `igraph_es_t`. [VertexSelector] and [EdgeSelector] describe
+//! the same sets in Rust; [VertexSelector::to_raw] and [EdgeSelector::to_raw]
+//! build the C selectors, which borrow the selector they come from and are
+//! released with `igraph_vs_destroy`/`igraph_es_destroy` when dropped.
+//!
+//! [Graph::vertices] and [Graph::edges] walk a selector with igraph's
+//! `igraph_vit_t`/`igraph_eit_t` iterators, exposed as Rust iterators. They, like the
+//! other methods taking a graph, first check the ids in the selector against the graph.
+
+use std::{iter::FusedIterator, marker::PhantomData, mem, ops::Range};
+
+use igraph_sys::{
+    igraph_edgeorder_type_t_IGRAPH_EDGEORDER_ID, igraph_eit_create, igraph_eit_destroy,
+    igraph_eit_t, igraph_eit_type_t_IGRAPH_EIT_RANGE, igraph_error_t, igraph_es_1, igraph_es_all,
+    igraph_es_all_between, igraph_es_as_vector, igraph_es_destroy, igraph_es_incident,
+    igraph_es_none, igraph_es_pairs, igraph_es_path, igraph_es_range, igraph_es_size, igraph_es_t,
+    igraph_es_vector, igraph_vector_int_t, igraph_vector_int_view, igraph_vit_create,
+    igraph_vit_destroy, igraph_vit_t, igraph_vit_type_t_IGRAPH_VIT_RANGE, igraph_vs_1,
+    igraph_vs_adj, igraph_vs_all, igraph_vs_as_vector, igraph_vs_destroy, igraph_vs_nonadj,
+    igraph_vs_none, igraph_vs_range, igraph_vs_size, igraph_vs_t, igraph_vs_vector,
+};
+
+use crate::{
+    EdgeId, Graph, IgraphError, Result, VertexId,
+    error::{call, check},
+    graph::Directedness,
+    modes::{Loops, NeighborMode},
+    thread,
+    vector::{Vector, VectorView},
+};
+
+/// A set of vertices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VertexSelector<'a> {
+    /// Every vertex, in increasing id order.
+    All,
+    /// No vertex.
+    None,
+    /// A single vertex.
+    Single(VertexId),
+    /// The given vertices, in the given order.
+    List(&'a [VertexId]),
+    /// The vertices with ids in the half-open range.
+    Range(Range<VertexId>),
+    /// The neighbors of a vertex, each listed once.
+    Adjacent(VertexId, NeighborMode),
+    /// The vertices that are not neighbors of a vertex.
+    NonAdjacent(VertexId, NeighborMode),
+}
+
+/// A set of edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EdgeSelector<'a> {
+    /// Every edge, in increasing id order.
+    All,
+    /// No edge.
+    None,
+    /// A single edge.
+    Single(EdgeId),
+    /// The given edges, in the given order.
+    List(&'a [EdgeId]),
+    /// The edges with ids in the half-open range.
+    Range(Range<EdgeId>),
+    /// The edges incident on a vertex, self-loops listed once.
+    Incident(VertexId, NeighborMode),
+    /// One edge between each pair of vertices, respecting directions in directed graphs.
+    Pairs(&'a [(VertexId, VertexId)]),
+    /// The edges along a path given by its vertices, respecting directions in directed graphs.
+    Path(&'a [VertexId]),
+    /// Every edge from the first vertex to the second, respecting directions in directed graphs.
+    AllBetween(VertexId, VertexId),
+}
+
+/// An initialized `igraph_vs_t` built from a [VertexSelector].
+pub struct RawVertexSelector<'a> {
+    inner: igraph_vs_t,
+    // `igraph_vs_vector` keeps a pointer to the vector struct, which must therefore stay put.
+    _view: Option<Box<igraph_vector_int_t>>,
+    selector: PhantomData<&'a [VertexId]>,
+}
+
+impl RawVertexSelector<'_> {
+    /// The selector to pass to igraph functions, which take it by value.
+    pub fn as_raw(&self) -> igraph_vs_t {
+        self.inner
+    }
+}
+
+impl Drop for RawVertexSelector<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_vs_destroy(&mut self.inner);
+        }
+    }
+}
+
+/// An initialized `igraph_es_t` built from an [EdgeSelector].
+pub struct RawEdgeSelector<'a> {
+    inner: igraph_es_t,
+    // `igraph_es_vector` keeps a pointer to the vector struct, which must therefore stay put.
+    _view: Option<Box<igraph_vector_int_t>>,
+    selector: PhantomData<&'a [EdgeId]>,
+}
+
+impl RawEdgeSelector<'_> {
+    /// The selector to pass to igraph functions, which take it by value.
+    pub fn as_raw(&self) -> igraph_es_t {
+        self.inner
+    }
+}
+
+impl Drop for RawEdgeSelector<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_es_destroy(&mut self.inner);
+        }
+    }
+}
+
+impl<'a> VertexSelector<'a> {
+    /// Builds the C selector; a [VertexSelector::List] is passed to igraph without copying.
+    pub fn to_raw(&self) -> Result<RawVertexSelector<'a>> {
+        let mut inner = unsafe { mem::zeroed::<igraph_vs_t>() };
+        let mut view = None;
+        let code: igraph_error_t = thread::serialized(|| unsafe {
+            match *self {
+                Self::All => igraph_vs_all(&mut inner),
+                Self::None => igraph_vs_none(&mut inner),
+                Self::Single(v) => igraph_vs_1(&mut inner, v.get()),
+                Self::List(vs) => {
+                    let vs = VertexId::as_raw_slice(vs);
+                    let view: &igraph_vector_int_t = view.insert(Box::new(igraph_vector_int_view(
+                        vs.as_ptr(),
+                        vs.len() as i64,
+                    )));
+                    igraph_vs_vector(&mut inner, view)
+                }
+                Self::Range(ref range) => {
+                    igraph_vs_range(&mut inner, range.start.get(), range.end.get())
+                }
+                Self::Adjacent(v, mode) => {
+                    igraph_vs_adj(&mut inner, v.get(), mode.raw(), Loops::Once.raw(), false)
+                }
+                Self::NonAdjacent(v, mode) => igraph_vs_nonadj(&mut inner, v.get(), mode.raw()),
+            }
+        });
+        check(code)?;
+        Ok(RawVertexSelector {
+            inner,
+            _view: view,
+            selector: PhantomData,
+        })
+    }
+
+    /// Fails with [IgraphError::InvalidVertexId] if the selector names a vertex that
+    /// `graph` does not have.
+    pub fn check<D: Directedness>(&self, graph: &Graph<D>) -> Result<()> {
+        match *self {
+            Self::All | Self::None => Ok(()),
+            Self::Single(v) | Self::Adjacent(v, _) | Self::NonAdjacent(v, _) => {
+                graph.check_vertex(v).map(drop)
+            }
+            Self::List(vs) => vs.iter().try_for_each(|&v| graph.check_vertex(v).map(drop)),
+            Self::Range(ref range) => check_range(range, graph.num_vertices(), |reason| {
+                IgraphError::invalid_vertex(reason)
+            }),
+        }
+    }
+
+    /// Builds the C selector after checking it against `graph`.
+    pub(crate) fn to_checked_raw<D: Directedness>(
+        &self,
+        graph: &Graph<D>,
+    ) -> Result<RawVertexSelector<'a>> {
+        self.check(graph)?;
+        self.to_raw()
+    }
+
+    /// The number of vertices selected in `graph`.
+    pub fn size<D: Directedness>(&self, graph: &Graph<D>) -> Result<usize> {
+        let raw = self.to_checked_raw(graph)?;
+        let mut size = 0;
+        call(|| unsafe { igraph_vs_size(graph.as_ptr(), &raw.inner, &mut size) })?;
+        Ok(size as usize)
+    }
+
+    /// The ids of the vertices selected in `graph`, via `igraph_vs_as_vector`.
+    pub fn expand<D: Directedness>(&self, graph: &Graph<D>) -> Result<Vec<VertexId>> {
+        let raw = self.to_checked_raw(graph)?;
+        let mut ids = Vector::<i64>::new()?;
+        call(|| unsafe { igraph_vs_as_vector(graph.as_ptr(), raw.as_raw(), ids.as_mut_ptr()) })?;
+        Ok(ids.iter().copied().map(VertexId::from_raw).collect())
+    }
+}
+
+impl<'a> EdgeSelector<'a> {
+    /// Builds the C selector; an [EdgeSelector::List] is passed to igraph without copying,
+    /// while pairs and paths are copied by igraph.
+    pub fn to_raw(&self) -> Result<RawEdgeSelector<'a>> {
+        let mut inner = unsafe { mem::zeroed::<igraph_es_t>() };
+        let mut view = None;
+        let code: igraph_error_t = thread::serialized(|| unsafe {
+            match *self {
+                Self::All => igraph_es_all(&mut inner, igraph_edgeorder_type_t_IGRAPH_EDGEORDER_ID),
+                Self::None => igraph_es_none(&mut inner),
+                Self::Single(e) => igraph_es_1(&mut inner, e.get()),
+                Self::List(es) => {
+                    let es = EdgeId::as_raw_slice(es);
+                    let view: &igraph_vector_int_t = view.insert(Box::new(igraph_vector_int_view(
+                        es.as_ptr(),
+                        es.len() as i64,
+                    )));
+                    igraph_es_vector(&mut inner, view)
+                }
+                Self::Range(ref range) => {
+                    igraph_es_range(&mut inner, range.start.get(), range.end.get())
+                }
+                Self::Incident(v, mode) => {
+                    igraph_es_incident(&mut inner, v.get(), mode.raw(), Loops::Once.raw())
+                }
+                Self::Pairs(pairs) => {
+                    let flat: Vec<i64> = pairs
+                        .iter()
+                        .flat_map(|&(u, v)| [u.get(), v.get()])
+                        .collect();
+                    igraph_es_pairs(&mut inner, VectorView::new(&flat).as_ptr(), true)
+                }
+                Self::Path(path) => igraph_es_path(
+                    &mut inner,
+                    VectorView::new(VertexId::as_raw_slice(path)).as_ptr(),
+                    true,
+                ),
+                Self::AllBetween(from, to) => {
+                    igraph_es_all_between(&mut inner, from.get(), to.get(), true)
+                }
+            }
+        });
+        check(code)?;
+        Ok(RawEdgeSelector {
+            inner,
+            _view: view,
+            selector: PhantomData,
+        })
+    }
+
+    /// Fails with [IgraphError::InvalidEdgeId] or [IgraphError::InvalidVertexId] if the
+    /// selector names an edge or a vertex that `graph` does not have.
+    pub fn check<D: Directedness>(&self, graph: &Graph<D>) -> Result<()> {
+        match *self {
+            Self::All | Self::None => Ok(()),
+            Self::Single(e) => graph.check_edge(e).map(drop),
+            Self::List(es) => es.iter().try_for_each(|&e| graph.check_edge(e).map(drop)),
+            Self::Range(ref range) => check_range(range, graph.num_edges(), |reason| {
+                IgraphError::invalid_edge(reason)
+            }),
+            Self::Incident(v, _) => graph.check_vertex(v).map(drop),
+            Self::Pairs(pairs) => pairs.iter().try_for_each(|&(u, v)| {
+                graph.check_vertex(u)?;
+                graph.check_vertex(v).map(drop)
+            }),
+            Self::Path(path) => path
+                .iter()
+                .try_for_each(|&v| graph.check_vertex(v).map(drop)),
+            Self::AllBetween(from, to) => {
+                graph.check_vertex(from)?;
+                graph.check_vertex(to).map(drop)
+            }
+        }
+    }
+
+    /// Builds the C selector after checking it against `graph`.
+    pub(crate) fn to_checked_raw<D: Directedness>(
+        &self,
+        graph: &Graph<D>,
+    ) -> Result<RawEdgeSelector<'a>> {
+        self.check(graph)?;
+        self.to_raw()
+    }
+
+    /// The number of edges selected in `graph`.
+    pub fn size<D: Directedness>(&self, graph: &Graph<D>) -> Result<usize> {
+        let raw = self.to_checked_raw(graph)?;
+        let mut size = 0;
+        call(|| unsafe { igraph_es_size(graph.as_ptr(), &raw.inner, &mut size) })?;
+        Ok(size as usize)
+    }
+
+    /// The ids of the edges selected in `graph`, via `igraph_es_as_vector`.
+    pub fn expand<D: Directedness>(&self, graph: &Graph<D>) -> Result<Vec<EdgeId>> {
+        let raw = self.to_checked_raw(graph)?;
+        let mut ids = Vector::<i64>::new()?;
+        call(|| unsafe { igraph_es_as_vector(graph.as_ptr(), raw.as_raw(), ids.as_mut_ptr()) })?;
+        Ok(ids.iter().copied().map(EdgeId::from_raw).collect())
+    }
+}
+
+/// Checks that `range` lies within the `len` elements of a graph.
+fn check_range<T: Copy + Into<usize> + std::fmt::Display>(
+    range: &Range<T>,
+    len: usize,
+    error: impl FnOnce(String) -> IgraphError,
+) -> Result<()> {
+    let (start, end): (usize, usize) = (range.start.into(), range.end.into());
+    if start <= end && end <= len {
+        Ok(())
+    } else {
+        Err(error(format!(
+            "the range {}..{} does not lie within the {len} elements of the graph",
+            range.start, range.end
+        )))
+    }
+}
+
+/// An iterator over the vertices of a [VertexSelector], created by [Graph::vertices].
+pub struct Vertices<'a> {
+    inner: igraph_vit_t,
+    front: i64,
+    back: i64,
+    // The iterator may read the vector owned by the selector.
+    _selector: RawVertexSelector<'a>,
+    graph: PhantomData<&'a Graph>,
+}
+
+/// An iterator over the edges of an [EdgeSelector], created by [Graph::edges].
+pub struct Edges<'a> {
+    inner: igraph_eit_t,
+    front: i64,
+    back: i64,
+    // The iterator may read the vector owned by the selector.
+    _selector: RawEdgeSelector<'a>,
+    graph: PhantomData<&'a Graph>,
+}
+
+macro_rules! impl_iterator {
+    ($name:ident, $item:ty, $range:ident, $destroy:ident) => {
+        impl $name<'_> {
+            /// The element at position `pos`, as `IGRAPH_VIT_GET`/`IGRAPH_EIT_GET` do.
+            fn get(&self, pos: i64) -> $item {
+                <$item>::from_raw(if self.inner.type_ == $range {
+                    pos
+                } else {
+                    unsafe { *(*self.inner.vec).stor_begin.offset(pos as isize) }
+                })
+            }
+        }
+
+        impl Iterator for $name<'_> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                if self.front == self.back {
+                    return None;
+                }
+                let item = self.get(self.front);
+                self.front += 1;
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = (self.back - self.front) as usize;
+                (len, Some(len))
+            }
+        }
+
+        impl DoubleEndedIterator for $name<'_> {
+            fn next_back(&mut self) -> Option<$item> {
+                if self.front == self.back {
+                    return None;
+                }
+                self.back -= 1;
+                Some(self.get(self.back))
+            }
+        }
+
+        impl ExactSizeIterator for $name<'_> {}
+
+        impl FusedIterator for $name<'_> {}
+
+        impl Drop for $name<'_> {
+            fn drop(&mut self) {
+                unsafe {
+                    $destroy(&self.inner);
+                }
+            }
+        }
+    };
+}
+
+impl_iterator!(
+    Vertices,
+    VertexId,
+    igraph_vit_type_t_IGRAPH_VIT_RANGE,
+    igraph_vit_destroy
+);
+impl_iterator!(
+    Edges,
+    EdgeId,
+    igraph_eit_type_t_IGRAPH_EIT_RANGE,
+    igraph_eit_destroy
+);
+
+impl<D: Directedness> Graph<D> {
+    /// Iterates over the vertices selected by `selector`, with `igraph_vit_create`.
+    pub fn vertices<'a>(&'a self, selector: VertexSelector<'a>) -> Result<Vertices<'a>> {
+        let raw = selector.to_checked_raw(self)?;
+        let mut inner = unsafe { mem::zeroed::<igraph_vit_t>() };
+        call(|| unsafe { igraph_vit_create(self.as_ptr(), raw.as_raw(), &mut inner) })?;
+        Ok(Vertices {
+            front: inner.start,
+            back: inner.end,
+            inner,
+            _selector: raw,
+            graph: PhantomData,
+        })
+    }
+
+    /// Iterates over the edges selected by `selector`, with `igraph_eit_create`.
+    pub fn edges<'a>(&'a self, selector: EdgeSelector<'a>) -> Result<Edges<'a>> {
+        let raw = selector.to_checked_raw(self)?;
+        let mut inner = unsafe { mem::zeroed::<igraph_eit_t>() };
+        call(|| unsafe { igraph_eit_create(self.as_ptr(), raw.as_raw(), &mut inner) })?;
+        Ok(Edges {
+            front: inner.start,
+            back: inner.end,
+            inner,
+            _selector: raw,
+            graph: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_graph() -> Graph {
+        Graph::setup().unwrap();
+        let mut graph = Graph::new(4, false).unwrap();
+        graph
+            .add_edges_from_slice(&[(0, 1), (1, 2), (2, 3)])
+            .unwrap();
+        graph
+    }
+
+    fn vertices(ids: &[u32]) -> Vec<VertexId> {
+        ids.iter().map(|&v| v.into()).collect()
+    }
+
+    fn edges(ids: &[u32]) -> Vec<EdgeId> {
+        ids.iter().map(|&e| e.into()).collect()
+    }
+
+    #[test]
+    fn test_vertex_selectors() {
+        let graph = path_graph();
+        assert_eq!(
+            VertexSelector::All.expand(&graph).unwrap(),
+            vertices(&[0, 1, 2, 3])
+        );
+        assert_eq!(
+            VertexSelector::List(&vertices(&[3, 1]))
+                .expand(&graph)
+                .unwrap(),
+            vertices(&[3, 1])
+        );
+        assert_eq!(
+            VertexSelector::Range(1.into()..3.into())
+                .size(&graph)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            VertexSelector::Adjacent(1.into(), NeighborMode::All)
+                .expand(&graph)
+                .unwrap(),
+            vertices(&[0, 2])
+        );
+        assert!(matches!(
+            VertexSelector::Single(4.into()).expand(&graph),
+            Err(IgraphError::InvalidVertexId(_))
+        ));
+        assert!(
+            VertexSelector::Range(2.into()..5.into())
+                .check(&graph)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_edge_selectors() {
+        let graph = path_graph();
+        assert_eq!(
+            EdgeSelector::Path(&vertices(&[0, 1, 2]))
+                .expand(&graph)
+                .unwrap(),
+            edges(&[0, 1])
+        );
+        assert_eq!(
+            EdgeSelector::Pairs(&[(3.into(), 2.into())])
+                .expand(&graph)
+                .unwrap(),
+            edges(&[2])
+        );
+        assert_eq!(
+            EdgeSelector::Incident(2.into(), NeighborMode::All)
+                .size(&graph)
+                .unwrap(),
+            2
+        );
+        assert!(EdgeSelector::None.expand(&graph).unwrap().is_empty());
+        assert!(matches!(
+            EdgeSelector::List(&edges(&[0, 3])).check(&graph),
+            Err(IgraphError::InvalidEdgeId(_))
+        ));
+    }
+
+    #[test]
+    fn test_selector_iterators() {
+        let graph = path_graph();
+        let range = graph
+            .vertices(VertexSelector::Range(1.into()..4.into()))
+            .unwrap();
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.rev().collect::<Vec<_>>(), vertices(&[3, 2, 1]));
+
+        let list = edges(&[2, 0, 1]);
+        let mut iter = graph.edges(EdgeSelector::List(&list)).unwrap();
+        assert_eq!(iter.next_back(), Some(1.into()));
+        assert_eq!(iter.collect::<Vec<_>>(), edges(&[2, 0]));
+
+        let even: Vec<_> = graph
+            .vertices(VertexSelector::All)
+            .unwrap()
+            .filter(|v| v.index() % 2 == 0)
+            .collect();
+        assert_eq!(even, vertices(&[0, 2]));
+
+        let typed = Graph::<crate::Undirected>::try_from(graph).unwrap();
+        assert_eq!(typed.vertices(VertexSelector::All).unwrap().len(), 4);
+        assert_eq!(typed.edges(EdgeSelector::All).unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,503 @@
+//! Owned wrapper over igraph's sparse matrix type.
+//!
+//! A [SparseMatrix] wraps an `igraph_sparsemat_t`, which is either in triplet form,
+//! a list of `(row, col, value)` entries that is cheap to build, or in compressed
+//! sparse column (CSC) form, which most arithmetic requires. [SparseMatrix::compress]
+//! turns the former into the latter, summing duplicate entries.
+
+use std::{marker::PhantomData, mem};
+
+use igraph_sys::{
+    igraph_adjacency_t, igraph_adjacency_t_IGRAPH_ADJ_DIRECTED,
+    igraph_adjacency_t_IGRAPH_ADJ_LOWER, igraph_adjacency_t_IGRAPH_ADJ_MAX,
+    igraph_adjacency_t_IGRAPH_ADJ_MIN, igraph_adjacency_t_IGRAPH_ADJ_PLUS,
+    igraph_adjacency_t_IGRAPH_ADJ_UNDIRECTED, igraph_adjacency_t_IGRAPH_ADJ_UPPER, igraph_error_t,
+    igraph_get_adjacency_sparse, igraph_get_adjacency_t,
+    igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_BOTH,
+    igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_LOWER,
+    igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_UPPER, igraph_get_laplacian_sparse,
+    igraph_get_stochastic_sparse, igraph_laplacian_normalization_t,
+    igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_LEFT,
+    igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_RIGHT,
+    igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_SYMMETRIC,
+    igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_UNNORMALIZED, igraph_sparse_adjacency,
+    igraph_sparse_weighted_adjacency, igraph_sparsemat_add, igraph_sparsemat_as_matrix,
+    igraph_sparsemat_compress, igraph_sparsemat_count_nonzero, igraph_sparsemat_destroy,
+    igraph_sparsemat_dupl, igraph_sparsemat_entry, igraph_sparsemat_gaxpy, igraph_sparsemat_get,
+    igraph_sparsemat_init, igraph_sparsemat_init_copy, igraph_sparsemat_is_cc,
+    igraph_sparsemat_is_triplet, igraph_sparsemat_iterator_col, igraph_sparsemat_iterator_end,
+    igraph_sparsemat_iterator_get, igraph_sparsemat_iterator_init, igraph_sparsemat_iterator_next,
+    igraph_sparsemat_iterator_row, igraph_sparsemat_iterator_t, igraph_sparsemat_multiply,
+    igraph_sparsemat_multiply_by_dense, igraph_sparsemat_ncol, igraph_sparsemat_nrow,
+    igraph_sparsemat_scale, igraph_sparsemat_t, igraph_sparsemat_transpose,
+};
+
+use crate::{
+    Graph, IgraphError, Matrix, Result,
+    error::call,
+    graph::Directedness,
+    modes::{Loops, NeighborMode},
+    thread,
+    vector::{Vector, VectorView},
+};
+
+/// An initialized `igraph_sparsemat_t`, destroyed when dropped.
+pub struct SparseMatrix {
+    inner: igraph_sparsemat_t,
+}
+
+impl SparseMatrix {
+    /// Runs `init` on a zeroed `igraph_sparsemat_t`, taking ownership only if igraph initialized it successfully.
+    fn init_with(init: impl FnOnce(*mut igraph_sparsemat_t) -> igraph_error_t) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<igraph_sparsemat_t>() };
+        call(|| init(&mut inner))?;
+        Ok(Self { inner })
+    }
+
+    /// Creates an empty `nrow` by `ncol` matrix in triplet form, with room for `capacity` entries.
+    pub fn new(nrow: usize, ncol: usize, capacity: usize) -> Result<Self> {
+        Self::init_with(|m| unsafe {
+            igraph_sparsemat_init(m, nrow as i64, ncol as i64, capacity as i64)
+        })
+    }
+
+    /// Creates an `nrow` by `ncol` matrix in triplet form from `(row, col, value)` entries;
+    /// entries at the same position are summed when the matrix is compressed.
+    pub fn from_triplets(
+        nrow: usize,
+        ncol: usize,
+        triplets: &[(usize, usize, f64)],
+    ) -> Result<Self> {
+        let mut matrix = Self::new(nrow, ncol, triplets.len())?;
+        for &(row, col, value) in triplets {
+            matrix.push(row, col, value)?;
+        }
+        Ok(matrix)
+    }
+
+    /// Adds an entry to a matrix in triplet form.
+    pub fn push(&mut self, row: usize, col: usize, value: f64) -> Result<()> {
+        if !self.is_triplet() {
+            return Err(IgraphError::invalid_value(
+                "entries can only be added to a sparse matrix in triplet form",
+            ));
+        }
+        if row >= self.nrow() || col >= self.ncol() {
+            return Err(IgraphError::invalid_value(format!(
+                "entry ({}, {}) out of bounds for a {}x{} matrix",
+                row,
+                col,
+                self.nrow(),
+                self.ncol()
+            )));
+        }
+        call(|| unsafe { igraph_sparsemat_entry(&mut self.inner, row as i64, col as i64, value) })
+    }
+
+    /// Copies the matrix, keeping its form.
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::init_with(|m| unsafe { igraph_sparsemat_init_copy(m, &self.inner) })
+    }
+
+    pub fn nrow(&self) -> usize {
+        unsafe { igraph_sparsemat_nrow(&self.inner) as usize }
+    }
+
+    pub fn ncol(&self) -> usize {
+        unsafe { igraph_sparsemat_ncol(&self.inner) as usize }
+    }
+
+    pub fn is_triplet(&self) -> bool {
+        unsafe { igraph_sparsemat_is_triplet(&self.inner) }
+    }
+
+    /// Whether the matrix is in compressed sparse column form.
+    pub fn is_compressed(&self) -> bool {
+        unsafe { igraph_sparsemat_is_cc(&self.inner) }
+    }
+
+    /// The number of nonzero entries; in triplet form, duplicates are counted separately.
+    pub fn count_nonzero(&mut self) -> usize {
+        unsafe { igraph_sparsemat_count_nonzero(&mut self.inner) as usize }
+    }
+
+    /// The value at `(row, col)`, summing duplicates in triplet form; `None` if out of
+    /// bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        (row < self.nrow() && col < self.ncol())
+            .then(|| unsafe { igraph_sparsemat_get(&self.inner, row as i64, col as i64) })
+    }
+
+    /// The compressed sparse column form of the matrix, with duplicate entries summed.
+    pub fn compress(&self) -> Result<Self> {
+        let mut compressed = if self.is_triplet() {
+            Self::init_with(|m| unsafe { igraph_sparsemat_compress(&self.inner, m) })?
+        } else {
+            self.try_clone()?
+        };
+        call(|| unsafe { igraph_sparsemat_dupl(&mut compressed.inner) })?;
+        Ok(compressed)
+    }
+
+    pub fn transpose(&self) -> Result<Self> {
+        Self::init_with(|m| unsafe { igraph_sparsemat_transpose(&self.inner, m) })
+    }
+
+    /// Multiplies every entry by `by`.
+    pub fn scale(&mut self, by: f64) -> Result<()> {
+        call(|| unsafe { igraph_sparsemat_scale(&mut self.inner, by) })
+    }
+
+    /// The matrix product `self * other`; both must be compressed.
+    pub fn multiply(&self, other: &Self) -> Result<Self> {
+        self.require_compressed()?;
+        other.require_compressed()?;
+        Self::init_with(|m| unsafe { igraph_sparsemat_multiply(&self.inner, &other.inner, m) })
+    }
+
+    /// The linear combination `alpha * self + beta * other`; both must be compressed.
+    pub fn add(&self, other: &Self, alpha: f64, beta: f64) -> Result<Self> {
+        self.require_compressed()?;
+        other.require_compressed()?;
+        Self::init_with(|m| unsafe {
+            igraph_sparsemat_add(&self.inner, &other.inner, alpha, beta, m)
+        })
+    }
+
+    /// The product of the compressed matrix with the dense vector `x`.
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vector<f64>> {
+        self.require_compressed()?;
+        if x.len() != self.ncol() {
+            return Err(IgraphError::invalid_value(format!(
+                "vector of length {} cannot multiply a matrix with {} columns",
+                x.len(),
+                self.ncol()
+            )));
+        }
+        let mut res = Vector::zeros(self.nrow())?;
+        call(|| unsafe {
+            igraph_sparsemat_gaxpy(&self.inner, VectorView::new(x).as_ptr(), res.as_mut_ptr())
+        })?;
+        Ok(res)
+    }
+
+    /// The product of the compressed matrix with the dense matrix `b`.
+    pub fn mul_dense(&self, b: &Matrix<f64>) -> Result<Matrix<f64>> {
+        self.require_compressed()?;
+        if b.nrow() != self.ncol() {
+            return Err(IgraphError::invalid_value(format!(
+                "matrix with {} rows cannot multiply a matrix with {} columns",
+                b.nrow(),
+                self.ncol()
+            )));
+        }
+        let mut res = Matrix::zeros(self.nrow(), b.ncol())?;
+        call(|| unsafe {
+            igraph_sparsemat_multiply_by_dense(&self.inner, b.as_ptr(), res.as_mut_ptr())
+        })?;
+        Ok(res)
+    }
+
+    /// The dense equivalent of the matrix.
+    pub fn to_dense(&self) -> Result<Matrix<f64>> {
+        let mut res = Matrix::zeros(self.nrow(), self.ncol())?;
+        call(|| unsafe { igraph_sparsemat_as_matrix(res.as_mut_ptr(), &self.inner) })?;
+        Ok(res)
+    }
+
+    /// Iterates over the stored entries as `(row, col, value)`; in triplet form, in
+    /// insertion order and with duplicates, in compressed form, column by column.
+    pub fn nonzeros(&self) -> Result<Nonzeros<'_>> {
+        let mut it = unsafe { mem::zeroed::<igraph_sparsemat_iterator_t>() };
+        call(|| unsafe { igraph_sparsemat_iterator_init(&mut it, &self.inner) })?;
+        Ok(Nonzeros {
+            it,
+            matrix: PhantomData,
+        })
+    }
+
+    pub fn as_ptr(&self) -> *const igraph_sparsemat_t {
+        &self.inner
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut igraph_sparsemat_t {
+        &mut self.inner
+    }
+
+    fn require_compressed(&self) -> Result<()> {
+        if self.is_compressed() {
+            Ok(())
+        } else {
+            Err(IgraphError::invalid_value(
+                "the sparse matrix must be compressed, see SparseMatrix::compress",
+            ))
+        }
+    }
+}
+
+impl Drop for SparseMatrix {
+    fn drop(&mut self) {
+        unsafe {
+            igraph_sparsemat_destroy(&mut self.inner);
+        }
+    }
+}
+
+/// Panics if igraph cannot allocate the copy; use [SparseMatrix::try_clone] to handle the failure.
+impl Clone for SparseMatrix {
+    fn clone(&self) -> Self {
+        self.try_clone()
+            .expect("igraph failed to copy the sparse matrix")
+    }
+}
+
+/// Iterator over the entries of a [SparseMatrix], built on `igraph_sparsemat_iterator_t`.
+pub struct Nonzeros<'a> {
+    it: igraph_sparsemat_iterator_t,
+    matrix: PhantomData<&'a SparseMatrix>,
+}
+
+impl Iterator for Nonzeros<'_> {
+    type Item = (usize, usize, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            if igraph_sparsemat_iterator_end(&self.it) {
+                return None;
+            }
+            let item = (
+                igraph_sparsemat_iterator_row(&self.it) as usize,
+                igraph_sparsemat_iterator_col(&self.it) as usize,
+                igraph_sparsemat_iterator_get(&self.it),
+            );
+            igraph_sparsemat_iterator_next(&mut self.it);
+            Some(item)
+        }
+    }
+}
+
+/// How to build a graph from an adjacency matrix, mirroring `igraph_adjacency_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdjacencyMode {
+    /// A directed graph, the entry `(i, j)` giving the number of edges from `i` to `j`.
+    Directed,
+    /// An undirected graph from a symmetric matrix.
+    Undirected,
+    /// An undirected graph from the upper triangle, diagonal included.
+    Upper,
+    /// An undirected graph from the lower triangle, diagonal included.
+    Lower,
+    /// An undirected graph with `min(A(i, j), A(j, i))` edges between `i` and `j`.
+    Min,
+    /// An undirected graph with `A(i, j) + A(j, i)` edges between `i` and `j`.
+    Plus,
+    /// An undirected graph with `max(A(i, j), A(j, i))` edges between `i` and `j`.
+    Max,
+}
+
+impl AdjacencyMode {
+    fn raw(self) -> igraph_adjacency_t {
+        match self {
+            Self::Directed => igraph_adjacency_t_IGRAPH_ADJ_DIRECTED,
+            Self::Undirected => igraph_adjacency_t_IGRAPH_ADJ_UNDIRECTED,
+            Self::Upper => igraph_adjacency_t_IGRAPH_ADJ_UPPER,
+            Self::Lower => igraph_adjacency_t_IGRAPH_ADJ_LOWER,
+            Self::Min => igraph_adjacency_t_IGRAPH_ADJ_MIN,
+            Self::Plus => igraph_adjacency_t_IGRAPH_ADJ_PLUS,
+            Self::Max => igraph_adjacency_t_IGRAPH_ADJ_MAX,
+        }
+    }
+}
+
+/// Which part of the adjacency matrix of an undirected graph to fill, mirroring `igraph_get_adjacency_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdjacencyPart {
+    Upper,
+    Lower,
+    Both,
+}
+
+impl AdjacencyPart {
+    fn raw(self) -> igraph_get_adjacency_t {
+        match self {
+            Self::Upper => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_UPPER,
+            Self::Lower => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_LOWER,
+            Self::Both => igraph_get_adjacency_t_IGRAPH_GET_ADJACENCY_BOTH,
+        }
+    }
+}
+
+/// Normalization of a Laplacian matrix, mirroring `igraph_laplacian_normalization_t`;
+/// `A` is the adjacency matrix and `D` the diagonal matrix of degrees or strengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaplacianNormalization {
+    /// `L = D - A`.
+    Unnormalized,
+    /// `L = I - D^(-1/2) A D^(-1/2)`.
+    Symmetric,
+    /// `L = I - D^-1 A`.
+    Left,
+    /// `L = I - A D^-1`.
+    Right,
+}
+
+impl LaplacianNormalization {
+    fn raw(self) -> igraph_laplacian_normalization_t {
+        match self {
+            Self::Unnormalized => igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_UNNORMALIZED,
+            Self::Symmetric => igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_SYMMETRIC,
+            Self::Left => igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_LEFT,
+            Self::Right => igraph_laplacian_normalization_t_IGRAPH_LAPLACIAN_RIGHT,
+        }
+    }
+}
+
+impl Graph {
+    /// Builds a graph from a sparse adjacency matrix whose entries count the edges between
+    /// vertices, binding [igraph_sparse_adjacency](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_sparse_adjacency).
+    pub fn from_sparse_adjacency(
+        adjacency: &mut SparseMatrix,
+        mode: AdjacencyMode,
+        loops: Loops,
+    ) -> Result<Self> {
+        Self::init_with(|graph| unsafe {
+            igraph_sparse_adjacency(graph, adjacency.as_mut_ptr(), mode.raw(), loops.raw())
+        })
+    }
+
+    /// Builds a graph with one edge per nonzero entry of a sparse adjacency matrix, returning
+    /// the entries as edge weights, binding [igraph_sparse_weighted_adjacency](https://igraph.org/c/html/latest/igraph-Generators.html#igraph_sparse_weighted_adjacency).
+    pub fn from_sparse_weighted_adjacency(
+        adjacency: &mut SparseMatrix,
+        mode: AdjacencyMode,
+        loops: Loops,
+    ) -> Result<(Self, Vector<f64>)> {
+        let mut weights = Vector::new()?;
+        let graph = Self::init_with(|graph| unsafe {
+            igraph_sparse_weighted_adjacency(
+                graph,
+                adjacency.as_mut_ptr(),
+                mode.raw(),
+                weights.as_mut_ptr(),
+                loops.raw(),
+            )
+        })?;
+        Ok((graph, weights))
+    }
+}
+
+impl<D: Directedness> Graph<D> {
+    /// The adjacency matrix of the graph in compressed form, binding
+    /// [igraph_get_adjacency_sparse](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_get_adjacency_sparse).
+    pub fn get_adjacency_sparse(
+        &self,
+        part: AdjacencyPart,
+        weights: Option<&[f64]>,
+        loops: Loops,
+    ) -> Result<SparseMatrix> {
+        let weights = self.check_weights(weights)?;
+        let mut res = SparseMatrix::new(0, 0, 0)?;
+        call(|| unsafe {
+            igraph_get_adjacency_sparse(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                part.raw(),
+                weights.as_ptr(),
+                loops.raw(),
+            )
+        })?;
+        res.compress()
+    }
+
+    /// The Laplacian matrix of the graph in compressed form, binding
+    /// [igraph_get_laplacian_sparse](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_get_laplacian_sparse).
+    pub fn get_laplacian_sparse(
+        &self,
+        mode: NeighborMode,
+        normalization: LaplacianNormalization,
+        weights: Option<&[f64]>,
+    ) -> Result<SparseMatrix> {
+        let weights = self.check_weights(weights)?;
+        let mut res = SparseMatrix::new(0, 0, 0)?;
+        call(|| unsafe {
+            igraph_get_laplacian_sparse(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                mode.raw(),
+                normalization.raw(),
+                weights.as_ptr(),
+            )
+        })?;
+        res.compress()
+    }
+
+    /// The transition matrix of a random walk on the graph in compressed form, rows summing
+    /// to one unless `column_wise`, binding
+    /// [igraph_get_stochastic_sparse](https://igraph.org/c/html/latest/igraph-Structural.html#igraph_get_stochastic_sparse).
+    pub fn get_stochastic_sparse(
+        &self,
+        column_wise: bool,
+        weights: Option<&[f64]>,
+    ) -> Result<SparseMatrix> {
+        let weights = self.check_weights(weights)?;
+        let mut res = SparseMatrix::new(0, 0, 0)?;
+        call(|| unsafe {
+            igraph_get_stochastic_sparse(
+                self.as_ptr(),
+                res.as_mut_ptr(),
+                column_wise,
+                weights.as_ptr(),
+            )
+        })?;
+        res.compress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triplets_compress_and_multiply() {
+        let triplets =
+            SparseMatrix::from_triplets(2, 3, &[(0, 0, 1.0), (1, 2, 2.0), (1, 2, 3.0)]).unwrap();
+        assert!(triplets.is_triplet());
+
+        let csc = triplets.compress().unwrap();
+        assert!(csc.is_compressed());
+        assert_eq!(csc.get(1, 2), Some(5.0));
+        assert_eq!(
+            csc.nonzeros().unwrap().collect::<Vec<_>>(),
+            vec![(0, 0, 1.0), (1, 2, 5.0)]
+        );
+        assert_eq!(&*csc.mul_vec(&[1.0, 1.0, 2.0]).unwrap(), &[1.0, 10.0]);
+        assert_eq!(csc.transpose().unwrap().get(2, 1), Some(5.0));
+        assert_eq!(csc.get(0, 3), None);
+        assert!(matches!(
+            csc.mul_dense(&Matrix::zeros(2, 1).unwrap()),
+            Err(IgraphError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_adjacency_round_trip() {
+        Graph::setup().unwrap();
+        let mut graph = Graph::new(3, false).unwrap();
+        graph.add_edges_from_slice(&[(0, 1), (1, 2)]).unwrap();
+        let mut adjacency = graph
+            .get_adjacency_sparse(AdjacencyPart::Both, None, Loops::Twice)
+            .unwrap();
+        assert!(adjacency.is_compressed());
+        assert_eq!(adjacency.get(1, 0), Some(1.0));
+        assert_eq!(
+            &*adjacency.mul_vec(&[1.0, 2.0, 3.0]).unwrap(),
+            &[2.0, 4.0, 2.0]
+        );
+
+        let copy =
+            Graph::from_sparse_adjacency(&mut adjacency, AdjacencyMode::Undirected, Loops::Twice)
+                .unwrap();
+        assert_eq!(copy.num_edges(), 2);
+    }
+}
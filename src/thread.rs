@@ -0,0 +1,168 @@
+//! Library setup and the threading model.
+//!
+//! igraph keeps its error, warning, progress and interruption handlers, its default random
+//! generator and its attribute table in global variables, which are thread-local when
+//! libigraph was built with `IGRAPH_ENABLE_TLS` (the default of its CMake build wherever
+//! the compiler supports it). The safe layer sets the library up lazily, once per thread,
+//! before the first igraph object is created, so calling [Graph::setup](crate::Graph::setup)
+//! by hand is only needed to surface a version mismatch early.
+//!
+//! With thread-local storage each thread sees its own handlers, generator and attribute
+//! table, and [Graph](crate::Graph)s can be moved between threads: a graph installs the
+//! attribute table it was created with on the thread using it. [is_thread_safe] tells
+//! whether the linked library was built that way. When it was not, every thread shares
+//! that global state: the library is set up once per process, and the safe layer holds
+//! the process-wide [lock] during every igraph call, as well as for the whole scope of
+//! [rng::with_default_rng](crate::rng::with_default_rng) and the
+//! [interrupt](crate::interrupt) runners, so that threads take turns. Code calling igraph
+//! directly takes the same lock; it is reentrant, so safe calls can be made while
+//! holding it.
+
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    sync::{
+        Mutex, MutexGuard, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use igraph_sys::{igraph_rng_default, igraph_setup};
+
+use crate::{Result, error, error::check, version};
+
+thread_local! {
+    /// Whether [setup] completed on this thread.
+    static SET_UP: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Checks the version of the linked library, initializes it and installs the error
+/// handler, unless this already happened on the calling thread, or in the process when
+/// the library keeps its state in global variables.
+pub(crate) fn setup() -> Result<()> {
+    if SET_UP.with(Cell::get) {
+        return Ok(());
+    }
+    if is_thread_safe() {
+        version::check()?;
+        check(unsafe { igraph_setup() })?;
+    } else {
+        static PROCESS_SET_UP: AtomicBool = AtomicBool::new(false);
+        let _library = lock();
+        if !PROCESS_SET_UP.load(Ordering::Relaxed) {
+            version::check()?;
+            check(unsafe { igraph_setup() })?;
+            PROCESS_SET_UP.store(true, Ordering::Relaxed);
+        }
+    }
+    serialized(error::install_error_handler);
+    SET_UP.with(|set_up| set_up.set(true));
+    Ok(())
+}
+
+/// Sets up a thread that received a [Graph](crate::Graph) created elsewhere before it
+/// uses it. The graph proves that setup already succeeded on another thread, so the
+/// version matches and only the per-thread part of [setup] can run here.
+///
+/// Panics if that part fails: the thread would otherwise keep igraph's error handler,
+/// which aborts the process on the next failing call.
+pub(crate) fn setup_moved() {
+    if !SET_UP.with(Cell::get) {
+        setup().expect("igraph could not be set up on the thread a graph was moved to");
+    }
+}
+
+/// Whether the linked libigraph keeps its global state in thread-local storage.
+///
+/// Probed once per process by comparing the address of the default random generator
+/// as seen from the calling thread and from a fresh one.
+pub fn is_thread_safe() -> bool {
+    static THREAD_SAFE: OnceLock<bool> = OnceLock::new();
+    *THREAD_SAFE.get_or_init(|| {
+        let here = unsafe { igraph_rng_default() } as usize;
+        let there = std::thread::spawn(|| unsafe { igraph_rng_default() } as usize)
+            .join()
+            .expect("probing the default generator cannot panic");
+        here != there
+    })
+}
+
+static LIBRARY: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// The guard of [LIBRARY] while this thread holds it, and how many times it took it.
+    static HELD: RefCell<(Option<MutexGuard<'static, ()>>, usize)> = const { RefCell::new((None, 0)) };
+}
+
+/// Serializes the use of a libigraph without thread-local storage; holds nothing when
+/// the library [is_thread_safe].
+#[must_use = "igraph is only serialized while the guard is alive"]
+pub struct LibraryGuard {
+    held: bool,
+    // The mutex is released on the thread that took it.
+    thread: PhantomData<*const ()>,
+}
+
+/// Blocks until no other thread holds the library, if it needs to be serialized. The
+/// calling thread may take it again while holding it.
+pub fn lock() -> LibraryGuard {
+    let held = !is_thread_safe();
+    if held {
+        HELD.with(|held| {
+            let mut held = held.borrow_mut();
+            if held.1 == 0 {
+                held.0 = Some(
+                    LIBRARY
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner()),
+                );
+            }
+            held.1 += 1;
+        });
+    }
+    LibraryGuard {
+        held,
+        thread: PhantomData,
+    }
+}
+
+impl Drop for LibraryGuard {
+    fn drop(&mut self) {
+        if self.held {
+            let guard = HELD.with(|held| {
+                let mut held = held.borrow_mut();
+                held.1 -= 1;
+                if held.1 == 0 { held.0.take() } else { None }
+            });
+            drop(guard);
+        }
+    }
+}
+
+/// Runs `f`, which calls igraph, while holding the library.
+pub(crate) fn serialized<T>(f: impl FnOnce() -> T) -> T {
+    let _library = lock();
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+
+    #[test]
+    fn test_graphs_move_between_threads() {
+        let mut graph = Graph::new(3, false).unwrap();
+        graph.add_edges_from_slice(&[(0, 1), (1, 2)]).unwrap();
+        let graph = std::thread::spawn(move || {
+            let mut graph = graph;
+            // The lock is reentrant, so safe calls can be made while holding it.
+            let _library = lock();
+            graph.add_edge(2, 0).unwrap();
+            graph
+        })
+        .join()
+        .unwrap();
+        assert_eq!(graph.num_edges(), 3);
+    }
+}
@@ -0,0 +1,332 @@
+//! Owned and borrowed wrappers over igraph's vector types.
+//!
+//! [Vector] owns an initialized `igraph_vector_t` (for `f64`), `igraph_vector_int_t`
+//! (for `i64`) or `igraph_vector_bool_t` (for `bool`) and dereferences to a slice over
+//! its `stor_begin..end` storage, so reading and writing elements never goes through
+//! `igraph_vector_*_get`/`set`. [VectorView] wraps a Rust slice with
+//! [igraph_vector_view](https://igraph.org/c/html/latest/igraph-Data-structures.html#igraph_vector_view)
+//! to pass it to igraph, e.g. as edge weights, without copying.
+
+use std::{
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+use igraph_sys::{
+    igraph_error_t, igraph_vector_bool_destroy, igraph_vector_bool_init,
+    igraph_vector_bool_init_copy, igraph_vector_bool_push_back, igraph_vector_bool_resize,
+    igraph_vector_bool_t, igraph_vector_bool_view, igraph_vector_destroy, igraph_vector_init,
+    igraph_vector_init_copy, igraph_vector_int_destroy, igraph_vector_int_init,
+    igraph_vector_int_init_copy, igraph_vector_int_push_back, igraph_vector_int_resize,
+    igraph_vector_int_t, igraph_vector_int_view, igraph_vector_push_back, igraph_vector_resize,
+    igraph_vector_t, igraph_vector_view,
+};
+
+use crate::{Result, error::call, thread};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An element type for which igraph provides a vector type.
+pub trait Element: Copy + Default + sealed::Sealed {
+    /// The igraph vector type holding elements of this type.
+    type Raw;
+
+    #[doc(hidden)]
+    unsafe fn init(v: *mut Self::Raw, size: i64) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn init_copy(to: *mut Self::Raw, from: *const Self::Raw) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn destroy(v: *mut Self::Raw);
+    #[doc(hidden)]
+    unsafe fn resize(v: *mut Self::Raw, size: i64) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn push_back(v: *mut Self::Raw, e: Self) -> igraph_error_t;
+    #[doc(hidden)]
+    unsafe fn view(data: *const Self, len: i64) -> Self::Raw;
+    #[doc(hidden)]
+    fn storage(v: &Self::Raw) -> (*mut Self, *mut Self);
+}
+
+macro_rules! impl_element {
+    ($t:ty, $raw:ty, $init:ident, $init_copy:ident, $destroy:ident, $resize:ident, $push_back:ident, $view:ident) => {
+        impl sealed::Sealed for $t {}
+
+        impl Element for $t {
+            type Raw = $raw;
+
+            unsafe fn init(v: *mut $raw, size: i64) -> igraph_error_t {
+                unsafe { $init(v, size) }
+            }
+
+            unsafe fn init_copy(to: *mut $raw, from: *const $raw) -> igraph_error_t {
+                unsafe { $init_copy(to, from) }
+            }
+
+            unsafe fn destroy(v: *mut $raw) {
+                unsafe { $destroy(v) }
+            }
+
+            unsafe fn resize(v: *mut $raw, size: i64) -> igraph_error_t {
+                unsafe { $resize(v, size) }
+            }
+
+            unsafe fn push_back(v: *mut $raw, e: $t) -> igraph_error_t {
+                unsafe { $push_back(v, e) }
+            }
+
+            unsafe fn view(data: *const $t, len: i64) -> $raw {
+                unsafe { $view(data, len) }
+            }
+
+            fn storage(v: &$raw) -> (*mut $t, *mut $t) {
+                (v.stor_begin, v.end)
+            }
+        }
+    };
+}
+
+impl_element!(
+    f64,
+    igraph_vector_t,
+    igraph_vector_init,
+    igraph_vector_init_copy,
+    igraph_vector_destroy,
+    igraph_vector_resize,
+    igraph_vector_push_back,
+    igraph_vector_view
+);
+impl_element!(
+    i64,
+    igraph_vector_int_t,
+    igraph_vector_int_init,
+    igraph_vector_int_init_copy,
+    igraph_vector_int_destroy,
+    igraph_vector_int_resize,
+    igraph_vector_int_push_back,
+    igraph_vector_int_view
+);
+impl_element!(
+    bool,
+    igraph_vector_bool_t,
+    igraph_vector_bool_init,
+    igraph_vector_bool_init_copy,
+    igraph_vector_bool_destroy,
+    igraph_vector_bool_resize,
+    igraph_vector_bool_push_back,
+    igraph_vector_bool_view
+);
+
+/// The elements stored in `raw`, which must be an initialized vector or view.
+pub(crate) fn as_slice<T: Element>(raw: &T::Raw) -> &[T] {
+    let (begin, end) = T::storage(raw);
+    if begin.is_null() {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(begin, end.offset_from(begin) as usize) }
+    }
+}
+
+/// An initialized igraph vector, destroyed when dropped.
+pub struct Vector<T: Element> {
+    inner: T::Raw,
+}
+
+impl<T: Element> Vector<T> {
+    /// Creates an empty vector.
+    pub fn new() -> Result<Self> {
+        Self::zeros(0)
+    }
+
+    /// Creates a vector of `len` zeroes (or `false`s).
+    pub fn zeros(len: usize) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<T::Raw>() };
+        call(|| unsafe { T::init(&mut inner, len as i64) })?;
+        Ok(Self { inner })
+    }
+
+    /// Creates a vector holding a copy of `data`.
+    pub fn from_slice(data: &[T]) -> Result<Self> {
+        let mut vec = Self::zeros(data.len())?;
+        vec.copy_from_slice(data);
+        Ok(vec)
+    }
+
+    /// Copies the vector with `igraph_vector_*_init_copy`.
+    pub fn try_clone(&self) -> Result<Self> {
+        thread::setup()?;
+        let mut inner = unsafe { mem::zeroed::<T::Raw>() };
+        call(|| unsafe { T::init_copy(&mut inner, &self.inner) })?;
+        Ok(Self { inner })
+    }
+
+    /// Resizes the vector, filling new positions with zeroes (or `false`s).
+    pub fn resize(&mut self, len: usize) -> Result<()> {
+        let old_len = self.len();
+        call(|| unsafe { T::resize(&mut self.inner, len as i64) })?;
+        // igraph leaves the new positions uninitialized, so they are written before
+        // `Deref` can expose them.
+        let (begin, _) = T::storage(&self.inner);
+        for i in old_len..len {
+            unsafe { begin.add(i).write(T::default()) };
+        }
+        Ok(())
+    }
+
+    /// Appends `value` at the end of the vector.
+    pub fn push(&mut self, value: T) -> Result<()> {
+        call(|| unsafe { T::push_back(&mut self.inner, value) })
+    }
+
+    pub fn as_ptr(&self) -> *const T::Raw {
+        &self.inner
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T::Raw {
+        &mut self.inner
+    }
+}
+
+impl<T: Element> Drop for Vector<T> {
+    fn drop(&mut self) {
+        unsafe {
+            T::destroy(&mut self.inner);
+        }
+    }
+}
+
+impl<T: Element> Deref for Vector<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        as_slice::<T>(&self.inner)
+    }
+}
+
+impl<T: Element> DerefMut for Vector<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        let (begin, end) = T::storage(&self.inner);
+        if begin.is_null() {
+            &mut []
+        } else {
+            unsafe { std::slice::from_raw_parts_mut(begin, end.offset_from(begin) as usize) }
+        }
+    }
+}
+
+/// Panics if igraph cannot allocate the copy, like `Vec::clone` aborts when out of memory;
+/// use [Vector::try_clone] to handle the failure.
+impl<T: Element> Clone for Vector<T> {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("igraph failed to copy the vector")
+    }
+}
+
+impl<T: Element + fmt::Debug> fmt::Debug for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Element + PartialEq> PartialEq for Vector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+/// Panics if igraph cannot allocate the vector; use [Vector::from_slice] to handle the failure.
+impl<T: Element> From<&[T]> for Vector<T> {
+    fn from(data: &[T]) -> Self {
+        Self::from_slice(data).expect("igraph failed to allocate the vector")
+    }
+}
+
+/// Panics if igraph cannot allocate the vector; use [Vector::from_slice] to handle the failure.
+impl<T: Element> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::from(data.as_slice())
+    }
+}
+
+impl<T: Element> From<Vector<T>> for Vec<T> {
+    fn from(vec: Vector<T>) -> Self {
+        vec.to_vec()
+    }
+}
+
+/// Panics if igraph cannot allocate the vector.
+impl<T: Element> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+/// A read-only igraph vector borrowing the storage of a Rust slice, built with
+/// `igraph_vector_*_view`; it must not be passed where igraph resizes or destroys
+/// the vector.
+pub struct VectorView<'a, T: Element> {
+    inner: T::Raw,
+    data: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: Element> VectorView<'a, T> {
+    pub fn new(data: &'a [T]) -> Self {
+        Self {
+            inner: unsafe { T::view(data.as_ptr(), data.len() as i64) },
+            data: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const T::Raw {
+        &self.inner
+    }
+}
+
+impl<'a, T: Element> From<&'a [T]> for VectorView<'a, T> {
+    fn from(data: &'a [T]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T: Element> Deref for VectorView<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        as_slice::<T>(&self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_conversions() {
+        let vec = Vector::from(vec![1.5, 2.5, 3.5]);
+        assert_eq!(&*vec, &[1.5, 2.5, 3.5]);
+        let copy = vec.clone();
+        assert_eq!(Vec::from(copy), vec![1.5, 2.5, 3.5]);
+
+        let mut ints: Vector<i64> = (0..4).collect();
+        ints[2] = 10;
+        ints.push(7).unwrap();
+        assert_eq!(Vec::from(ints), vec![0, 1, 10, 3, 7]);
+
+        assert!(Vector::<bool>::new().unwrap().is_empty());
+
+        let mut flags = Vector::from(vec![true]);
+        flags.resize(3).unwrap();
+        assert_eq!(&*flags, &[true, false, false]);
+    }
+
+    #[test]
+    fn test_vector_view_does_not_copy() {
+        let weights = [0.5, 1.0, 2.0];
+        let view = VectorView::new(&weights);
+        assert_eq!(view.as_ptr_range(), weights.as_ptr_range());
+    }
+}
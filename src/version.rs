@@ -0,0 +1,74 @@
+//! Versions of the igraph headers the bindings come from and of the library linked at runtime.
+
+use std::{
+    ffi::{CStr, c_char, c_int},
+    fmt,
+};
+
+use igraph_sys::{
+    IGRAPH_VERSION_MAJOR, IGRAPH_VERSION_MINOR, IGRAPH_VERSION_PATCH, igraph_version,
+};
+
+use crate::{IgraphError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The version of the headers the bindings were generated from, i.e. the `IGRAPH_VERSION_*` constants.
+pub fn bindings() -> Version {
+    Version {
+        major: IGRAPH_VERSION_MAJOR,
+        minor: IGRAPH_VERSION_MINOR,
+        patch: IGRAPH_VERSION_PATCH,
+    }
+}
+
+/// The version of the libigraph actually linked, as reported by
+/// [igraph_version](https://igraph.org/c/html/latest/igraph-Basic.html#igraph_version).
+pub fn linked() -> Version {
+    let (mut major, mut minor, mut patch): (c_int, c_int, c_int) = (0, 0, 0);
+    unsafe {
+        igraph_version(std::ptr::null_mut(), &mut major, &mut minor, &mut patch);
+    }
+    Version {
+        major: major as u32,
+        minor: minor as u32,
+        patch: patch as u32,
+    }
+}
+
+/// The full version string of the linked libigraph, including any pre-release tag.
+pub fn linked_string() -> String {
+    let mut string: *const c_char = std::ptr::null();
+    unsafe {
+        igraph_version(
+            &mut string,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        );
+        CStr::from_ptr(string).to_string_lossy().into_owned()
+    }
+}
+
+/// Fails with [IgraphError::VersionMismatch] unless the linked libigraph has the same
+/// major and minor version as the bindings, since struct layouts and function signatures
+/// may change between minor releases.
+pub fn check() -> Result<()> {
+    let (bindings, linked) = (bindings(), linked());
+    if (bindings.major, bindings.minor) == (linked.major, linked.minor) {
+        Ok(())
+    } else {
+        Err(IgraphError::VersionMismatch { bindings, linked })
+    }
+}